@@ -0,0 +1,101 @@
+/// Generates the registry of solutions that the multi-day runner executes in-process.
+///
+/// Every `src/bin/NN.rs` and `src/bin/YYYY-NN.rs` is included as a module of the runner binary,
+/// and its `SOLUTION` entry point (emitted by the `solution!` macro) is collected into `SOLUTIONS`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // NOTE: watching the directory picks up added and removed days, not only edits.
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+    println!("cargo:rerun-if-env-changed=AOC_YEAR");
+
+    let configured_year = env::var("AOC_YEAR").ok().and_then(|year| year.parse().ok());
+
+    // days are keyed by year, which is `0` for days of the year set via `AOC_YEAR`.
+    let mut days: Vec<((u16, u8), String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "rs" {
+                        return None;
+                    }
+                    let key = parse_key(path.file_stem()?.to_str()?, configured_year)?;
+                    Some((key, path.display().to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+
+    // NOTE: e.g. `05.rs` and `2025-05.rs` are the same day if `AOC_YEAR` is 2025.
+    if let Some([(_, a), (_, b)]) = days.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        panic!("{a} and {b} are solutions of the same day, remove one of them.");
+    }
+
+    let mut lines: Vec<String> =
+        vec!["// @generated by `build.rs` from the contents of `src/bin`.".into()];
+
+    for (key, path) in &days {
+        lines.push(String::new());
+        lines.push("#[cfg(not(test))]".into());
+        lines.push("#[allow(dead_code, clippy::all)]".into());
+        lines.push(format!("#[path = {path:?}]"));
+        lines.push(format!("mod {};", module_name(*key)));
+    }
+
+    let entries = days
+        .iter()
+        .map(|(key, _)| format!("{}::SOLUTION", module_name(*key)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    lines.push(String::new());
+    lines.push(
+        "/// All solutions that were present when the runner was compiled, sorted by year and day."
+            .into(),
+    );
+    lines.push("#[cfg(not(test))]".into());
+    lines.push(format!(
+        "pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[{entries}];"
+    ));
+    lines.push(String::new());
+    lines.push("// NOTE: day modules carry their own tests, don't run them twice.".into());
+    lines.push("#[cfg(test)]".into());
+    lines.push("pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];".into());
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("solutions.rs"),
+        lines.join("\n") + "\n",
+    )
+    .unwrap();
+}
+
+/// Parses the file stem of a solution, e.g. `05` or `2024-05`. Days of `configured_year` are keyed like `05`.
+fn parse_key(stem: &str, configured_year: Option<u16>) -> Option<(u16, u8)> {
+    let (year, day) = match stem.split_once('-') {
+        Some((year, day)) => (year.parse::<u16>().ok().filter(|x| *x >= 2015)?, day),
+        None => (0, stem),
+    };
+    let year = if Some(year) == configured_year {
+        0
+    } else {
+        year
+    };
+
+    let day = day.parse::<u8>().ok()?;
+    (1..=25).contains(&day).then_some((year, day))
+}
+
+fn module_name((year, day): (u16, u8)) -> String {
+    match year {
+        0 => format!("day_{day:02}"),
+        year => format!("day_{year}_{day:02}"),
+    }
+}
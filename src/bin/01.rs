@@ -1,28 +1,33 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(1, parse = parse);
 
 use std::ops::Neg;
 
-use atoi::atoi;
+use advent_of_code::parse::{ParseError, ParseErrorKind, integer, parse_lines};
 
-fn parse(input: &str) -> Vec<i32> {
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            let num = &line[1..];
-            let num: i32 = atoi(num.as_bytes()).expect("Always a number.");
-            if line.starts_with("L") {
-                num.neg()
-            } else {
-                num
-            }
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse_lines(input.trim(), |line| {
+        let Some(distance) = line.strip_prefix(['L', 'R']) else {
+            let kind = ParseErrorKind::ExpectedToken {
+                expected: "`L` or `R`",
+                found: line.chars().next().map(String::from),
+            };
+            return Err(ParseError::new(line, 0, kind));
+        };
+
+        let num: i32 = integer(distance).map_err(|e| e.within(line))?;
+        Ok(if line.starts_with("L") {
+            num.neg()
+        } else {
+            num
         })
-        .collect()
+    })
+    .collect::<Result<_, _>>()
+    .map_err(|e| e.within(input))
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
     let mut position = 50;
-    let nums = parse(input);
+    let nums = parse(input)?;
     let mut zeros = 0;
 
     for rotation in nums {
@@ -32,12 +37,12 @@ pub fn part_one(input: &str) -> Option<u64> {
             zeros += 1;
         }
     }
-    Some(zeros)
+    Ok(zeros)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+pub fn part_two(input: &str) -> Result<i32, ParseError> {
     let mut position = 50;
-    let nums = parse(input);
+    let nums = parse(input)?;
     let mut spins = 0;
 
     for rotation in nums {
@@ -50,7 +55,7 @@ pub fn part_two(input: &str) -> Option<i32> {
         position = (position + rotation).rem_euclid(100);
     }
 
-    Some(spins)
+    Ok(spins)
 }
 
 #[cfg(test)]
@@ -60,12 +65,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+        assert_eq!(result, Ok(3));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
+        assert_eq!(result, Ok(6));
     }
 }
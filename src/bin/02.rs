@@ -1,6 +1,6 @@
 use std::ops::RangeInclusive;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, parse = parse);
 
 fn parse(input: &str) -> Vec<RangeInclusive<u64>> {
     input
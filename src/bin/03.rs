@@ -1,24 +1,17 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, parse = parse);
 
-fn parse(input: &str) -> Vec<Vec<u8>> {
-    input
-        .lines()
-        .map(|line| {
-            line.bytes()
-                .map(|value| match value {
-                    x @ b'1'..=b'9' => x - b'0',
-                    _ => unreachable!(),
-                })
-                .collect()
-        })
-        .collect()
+use advent_of_code::grid::Grid;
+use advent_of_code::parse::{ParseError, digit_grid};
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    digit_grid(input)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let powerbanks = parse(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let powerbanks = parse(input)?;
 
     let val = powerbanks
-        .iter()
+        .rows()
         .map(|powerbank| {
             let mut biggest = (0, 0);
             for (i, &value) in powerbank.iter().enumerate().take(powerbank.len() - 1) {
@@ -41,14 +34,14 @@ pub fn part_one(input: &str) -> Option<u64> {
         })
         .sum();
 
-    Some(val)
+    Ok(val)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let powerbanks = parse(input);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let powerbanks = parse(input)?;
 
     let val = powerbanks
-        .iter()
+        .rows()
         .map(|powerbank| {
             let mut buffer_end: i32 = 11;
             let mut values: Vec<u8> = Vec::with_capacity(11);
@@ -86,7 +79,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             values.iter().fold(0_u64, |acc, x| acc * 10 + *x as u64)
         })
         .sum();
-    Some(val)
+    Ok(val)
 }
 
 #[cfg(test)]
@@ -96,12 +89,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
+        assert_eq!(result, Ok(357));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
+        assert_eq!(result, Ok(3121910778619));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, parse = parse);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Cell {
@@ -1,86 +1,36 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse);
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct Range {
-    start: u64,
-    end: u64,
-}
-
-impl Range {
-    #[inline]
-    fn contains(&self, other: &Range) -> bool {
-        self.start > other.end || self.end < other.start
-    }
-}
+use advent_of_code::interval_set::IntervalSet;
+use advent_of_code::parse::{ParseError, integer, parse_lines, sections, tuple};
 
-fn parse(input: &str) -> (Vec<Range>, Vec<u64>) {
-    let (ranges, values) = input.split_once("\n\n").unwrap();
+fn parse(input: &str) -> Result<(IntervalSet<u64>, Vec<u64>), ParseError> {
+    let mut sections = sections(input);
+    let (ranges, values) = (sections.next().unwrap_or(""), sections.next().unwrap_or(""));
 
-    let ranges = ranges
-        .lines()
-        .map(|line| {
-            let (start, end) = line.split_once("-").unwrap();
-            Range {
-                start: start.parse().unwrap(),
-                end: end.parse().unwrap(),
-            }
-        })
-        .collect();
+    let ranges = parse_lines(ranges, |line| {
+        tuple(line, "-").map(|(start, end)| start..=end)
+    })
+    .collect::<Result<_, ParseError>>()
+    .map_err(|e| e.within(input))?;
 
-    let values = values.lines().map(|line| line.parse().unwrap()).collect();
+    let values = parse_lines(values, integer)
+        .collect::<Result<_, ParseError>>()
+        .map_err(|e| e.within(input))?;
 
-    (ranges, values)
-}
-
-fn find_overlap(ranges: &[Range]) -> Option<(usize, usize)> {
-    for (i, range1) in ranges.iter().enumerate().rev() {
-        for (j, range2) in ranges.iter().enumerate().skip(i + 1) {
-            if !range1.contains(range2) {
-                return Some((i, j));
-            }
-        }
-    }
-    None
+    Ok((ranges, values))
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (ranges, mut values) = parse(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let (ranges, values) = parse(input)?;
 
-    values.sort();
-
-    let mut total = 0;
-    'ingredient: for value in values {
-        for range in &ranges {
-            if (range.start..=range.end).contains(&value) {
-                total += 1;
-                continue 'ingredient;
-            }
-        }
-    }
-    Some(total)
+    let fresh = values.into_iter().filter(|value| ranges.contains(*value));
+    Ok(fresh.count() as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (mut ranges, _) = parse(input);
-
-    ranges.sort();
-
-    while let Some((i, j)) = find_overlap(&ranges) {
-        let range1 = ranges.remove(j);
-        let range2 = ranges.remove(i);
-
-        let start = range1.start.min(range2.start);
-        let end = range1.end.max(range2.end);
-
-        let combined = Range { start, end };
-        ranges.push(combined);
-    }
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let (ranges, _) = parse(input)?;
 
-    let count = ranges
-        .into_iter()
-        .map(|range| range.end - range.start + 1)
-        .sum();
-    Some(count)
+    Ok(ranges.len())
 }
 
 #[cfg(test)]
@@ -90,12 +40,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+        assert_eq!(result, Ok(3));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
+        assert_eq!(result, Ok(14));
     }
 }
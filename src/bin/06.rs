@@ -1,4 +1,6 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, parse = parse);
+
+use advent_of_code::parse::{ParseError, ParseErrorKind, integer};
 
 #[derive(Debug, PartialEq)]
 enum Operand {
@@ -12,53 +14,109 @@ struct Equation {
     operand: Operand,
 }
 
-fn parse(input: &str) -> Vec<Equation> {
+fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
     let mut iter = input.lines().rev();
 
+    let Some(operators) = iter.next() else {
+        return Err(missing_operators(input));
+    };
+
     let mut equations = Vec::new();
-    iter.next().iter().for_each(|line| {
-        for sign in line.split_ascii_whitespace() {
-            match sign {
-                "+" => equations.push(Equation {
-                    operand: Operand::Add,
-                    nums: Vec::new(),
-                }),
-                _ => equations.push(Equation {
-                    nums: Vec::new(),
-                    operand: Operand::Multiply,
-                }),
+    for sign in operators.split_ascii_whitespace() {
+        let operand = match sign {
+            "+" => Operand::Add,
+            "*" => Operand::Multiply,
+            _ => return Err(unknown_operator(sign, 0).within(input)),
+        };
+        equations.push(Equation {
+            nums: Vec::new(),
+            operand,
+        });
+    }
+
+    for line in iter {
+        let mut found = 0;
+        for (i, val) in line.split_ascii_whitespace().enumerate() {
+            let num = integer(val).map_err(|e| e.within(input))?;
+            if let Some(equation) = equations.get_mut(i) {
+                equation.nums.push(num);
             }
+            found = i + 1;
         }
-    });
 
-    iter.for_each(|line| {
-        for (i, val) in line.split_ascii_whitespace().enumerate() {
-            equations[i].nums.push(val.parse().unwrap());
+        // every line needs a number for each operator.
+        if found != equations.len() {
+            let kind = ParseErrorKind::FieldCount {
+                expected: equations.len(),
+                found,
+            };
+            return Err(ParseError::new(line, line.len(), kind).within(input));
         }
-    });
-    equations
+    }
+
+    Ok(equations)
 }
 
-fn dumb_number_parse(input: &str) -> u64 {
-    let mut bytes: Vec<Vec<_>> = input.lines().map(|line| line.chars().collect()).collect();
+fn missing_operators(input: &str) -> ParseError {
+    let kind = ParseErrorKind::ExpectedToken {
+        expected: "a line of operators",
+        found: None,
+    };
+    ParseError::new(input, input.len(), kind)
+}
 
-    let mut operands = bytes.pop().unwrap();
+/// An error for the character at byte `offset` of `line`, which is neither `+` nor `*`.
+fn unknown_operator(line: &str, offset: usize) -> ParseError {
+    let kind = ParseErrorKind::ExpectedToken {
+        expected: "`+` or `*`",
+        found: line[offset..]
+            .split_ascii_whitespace()
+            .next()
+            .map(str::to_string),
+    };
+    ParseError::new(line, offset, kind)
+}
+
+fn dumb_number_parse(input: &str) -> Result<u64, ParseError> {
+    let mut rows: Vec<&str> = input.lines().collect();
+
+    let Some(operators) = rows.pop() else {
+        return Err(missing_operators(input));
+    };
+
+    // numbers are read column by column, so every line needs the width of the first one.
+    let width = rows.first().unwrap_or(&operators).len();
+    for line in rows.iter().chain([&operators]) {
+        if line.len() != width {
+            let kind = ParseErrorKind::LineLength {
+                expected: width,
+                found: line.len(),
+            };
+            return Err(ParseError::new(line, line.len().min(width), kind).within(input));
+        }
+    }
+
+    if let Some(offset) = operators
+        .bytes()
+        .position(|b| !matches!(b, b' ' | b'+' | b'*'))
+    {
+        return Err(unknown_operator(operators, offset).within(input));
+    }
 
     // delimit end of equations with special character picked at random
-    operands.push('#');
+    let operands = operators.bytes().chain([b'#']);
 
     let (total, _, _) = operands
-        .iter()
         .enumerate()
-        .fold((0, 0, '+'), |acc, (i, &operator)| {
+        .fold((0, 0, b'+'), |acc, (i, operator)| {
             let (total, partial, prev_op) = acc;
-            if operator == '#' {
+            if operator == b'#' {
                 (total + partial, 0, prev_op)
             } else {
-                let number = bytes.iter().fold(0, |acc, row| {
-                    let part = row[i];
+                let number = rows.iter().fold(0, |acc, row| {
+                    let part = row.as_bytes()[i];
                     if part.is_ascii_digit() {
-                        10 * acc + ((part.to_digit(10).unwrap()) as u64)
+                        10 * acc + (part - b'0') as u64
                     } else {
                         acc
                     }
@@ -70,19 +128,19 @@ fn dumb_number_parse(input: &str) -> u64 {
                 } else {
                     match (operator, prev_op) {
                         // mid equation
-                        (' ', '+') => (total, partial + number, prev_op),
-                        (' ', '*') => (total, partial * number, prev_op),
+                        (b' ', b'+') => (total, partial + number, prev_op),
+                        (b' ', b'*') => (total, partial * number, prev_op),
                         // new equation column
                         _ => (total + partial, number, operator),
                     }
                 }
             }
         });
-    total
+    Ok(total)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let equations = parse(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let equations = parse(input)?;
 
     let val = equations.iter().fold(0, |acc, equation| {
         acc + if equation.operand == Operand::Add {
@@ -91,13 +149,11 @@ pub fn part_one(input: &str) -> Option<u64> {
             equation.nums.iter().copied().product::<u64>()
         }
     });
-    Some(val)
+    Ok(val)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let total = dumb_number_parse(input);
-
-    Some(total)
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    dumb_number_parse(input)
 }
 
 #[cfg(test)]
@@ -107,12 +163,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
+        assert_eq!(result, Ok(4277556));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
+        assert_eq!(result, Ok(3263827));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, parse = Grid::from);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Cell {
@@ -1,7 +1,9 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, parse = |input| calculate_distances(&parse(input)));
 
 use std::{cmp::Reverse, collections::BinaryHeap};
 
+use advent_of_code::disjoint_set::DisjointSet;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Point {
     x: i64,
@@ -38,7 +40,7 @@ impl Eq for Distance {}
 
 impl PartialOrd for Distance {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.distance.partial_cmp(&other.distance)
+        Some(self.cmp(other))
     }
 }
 
@@ -67,7 +69,7 @@ fn parse(input: &str) -> Vec<Point> {
         .collect()
 }
 
-fn calculate_distances(points: &Vec<Point>) -> BinaryHeap<Reverse<Distance>> {
+fn calculate_distances(points: &[Point]) -> BinaryHeap<Reverse<Distance>> {
     let mut distance_mapping = BinaryHeap::new();
 
     for (i, point1) in points.iter().enumerate() {
@@ -83,89 +85,33 @@ fn calculate_distances(points: &Vec<Point>) -> BinaryHeap<Reverse<Distance>> {
     distance_mapping
 }
 
+/// Links the `link_times` closest pairs of boxes, whether or not they are in the same circuit already.
 fn link_boxes(
     mut distance_map: BinaryHeap<Reverse<Distance>>,
-    points: Vec<Point>,
+    points: &[Point],
     link_times: usize,
-) -> Vec<Vec<Point>> {
-    let mut link_actions = 0;
-    let mut circuits: Vec<Vec<Point>> = Vec::new();
-
-    for point in points {
-        circuits.push(vec![point]);
-    }
+) -> DisjointSet {
+    let mut circuits = DisjointSet::new(points.len());
 
-    while link_actions < link_times {
+    for _ in 0..link_times {
         let smallest_distance = distance_map.pop().unwrap().0;
-        link_actions += 1;
-        let (mut c1, mut c2) = (None, None);
-        for (index, circuit) in circuits.iter().enumerate() {
-            if circuit.contains(&smallest_distance.p1) {
-                c1 = Some(index);
-            }
-            if circuit.contains(&smallest_distance.p2) {
-                c2 = Some(index);
-            }
-        }
-
-        match (c1, c2) {
-            (None, Some(d2_index)) => circuits[d2_index].push(smallest_distance.p1),
-            (Some(d1_index), None) => circuits[d1_index].push(smallest_distance.p2),
-
-            (Some(d1_index), Some(d2_index)) => {
-                if d1_index != d2_index {
-                    let to_move = std::mem::take(&mut circuits[d2_index]); // Sometimes you get tired of asking for memory nicely
-                    circuits[d1_index].extend(to_move);
-
-                    circuits.remove(d2_index);
-                }
-            }
-            (None, None) => circuits.push(vec![smallest_distance.p1, smallest_distance.p2]),
-        }
+        circuits.union(smallest_distance.p1.id, smallest_distance.p2.id);
     }
 
     circuits
 }
 
-fn link_boxes_of_dumb(
-    mut distance_map: BinaryHeap<Reverse<Distance>>,
-    points: Vec<Point>,
-) -> (i64, i64) {
-    let mut circuits: Vec<Vec<Point>> = Vec::new();
-
-    for point in &points {
-        circuits.push(vec![*point]);
-    }
+/// Links the closest pairs of boxes until all are in one circuit, returning the x coordinates of the last pair.
+fn link_all_boxes(mut distance_map: BinaryHeap<Reverse<Distance>>, points: &[Point]) -> (i64, i64) {
+    let mut circuits = DisjointSet::new(points.len());
 
     loop {
         let smallest_distance = distance_map.pop().unwrap().0;
-        let (mut c1, mut c2) = (None, None);
-        for (index, circuit) in circuits.iter().enumerate() {
-            if circuit.contains(&smallest_distance.p1) {
-                c1 = Some(index);
-            }
-            if circuit.contains(&smallest_distance.p2) {
-                c2 = Some(index);
-            }
-        }
-
-        match (c1, c2) {
-            (None, Some(d2_index)) => circuits[d2_index].push(smallest_distance.p1),
-            (Some(d1_index), None) => circuits[d1_index].push(smallest_distance.p2),
-
-            (Some(d1_index), Some(d2_index)) => {
-                if d1_index != d2_index {
-                    let to_move = std::mem::take(&mut circuits[d2_index]); // Sometimes you get tired of asking for memory nicely
-                    circuits[d1_index].extend(to_move);
 
-                    if circuits[d1_index].len() == points.len() {
-                        return (smallest_distance.p1.x, smallest_distance.p2.x);
-                    }
-
-                    circuits.remove(d2_index);
-                }
-            }
-            (None, None) => circuits.push(vec![smallest_distance.p1, smallest_distance.p2]),
+        if circuits.union(smallest_distance.p1.id, smallest_distance.p2.id)
+            && circuits.component_count() == 1
+        {
+            return (smallest_distance.p1.x, smallest_distance.p2.x);
         }
     }
 }
@@ -180,18 +126,12 @@ pub fn part_one(input: &str) -> Option<u64> {
     #[cfg(not(test))]
     let link_times = 1000;
 
-    let mut circuits = link_boxes(distance_map, points, link_times);
+    let circuits = link_boxes(distance_map, &points, link_times);
 
-    circuits.sort_by_key(|a| a.len());
+    let mut sizes: Vec<usize> = circuits.component_sizes().collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
 
-    Some(
-        circuits
-            .iter()
-            .rev()
-            .take(3)
-            .map(|circuit| circuit.len() as u64)
-            .product(),
-    )
+    Some(sizes.iter().take(3).map(|size| *size as u64).product())
 }
 
 pub fn part_two(input: &str) -> Option<i64> {
@@ -199,7 +139,7 @@ pub fn part_two(input: &str) -> Option<i64> {
 
     let distance_map = calculate_distances(&points);
 
-    let (x1, x2) = link_boxes_of_dumb(distance_map, points);
+    let (x1, x2) = link_all_boxes(distance_map, &points);
     Some(x1 * x2)
 }
 
@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, parse = parse);
 
 #[derive(Debug, PartialEq)]
 struct Point {
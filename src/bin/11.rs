@@ -1,7 +1,8 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, parse = parse);
 
 use std::collections::VecDeque;
 
+use advent_of_code::parse::{ParseError, parse_lines, split_pair};
 use ahash::{HashMap, HashMapExt};
 
 fn path_count<'a>(
@@ -10,42 +11,52 @@ fn path_count<'a>(
     node: &'a str,
     mut dac: bool,
     mut fft: bool,
-) -> u64 {
+) -> Result<u64, String> {
     if node == "out" {
-        return if dac && fft { 1 } else { 0 };
+        return Ok(if dac && fft { 1 } else { 0 });
     }
     dac |= node == "dac";
     fft |= node == "fft";
     let key = (node, dac, fft);
     if !cache.contains_key(&key) {
-        let sub_counts = network[node]
+        let sub_counts = outputs(network, node)?
             .iter()
             .map(|x| path_count(cache, network, x, dac, fft))
-            .sum();
+            .sum::<Result<_, _>>()?;
         cache.insert(key, sub_counts);
     }
 
-    cache[&key]
+    Ok(cache[&key])
 }
 
-fn parse(input: &str) -> HashMap<&str, Vec<&str>> {
+fn outputs<'a>(
+    network: &'a HashMap<&str, Vec<&'a str>>,
+    node: &str,
+) -> Result<&'a [&'a str], String> {
+    network
+        .get(node)
+        .map(Vec::as_slice)
+        .ok_or_else(|| format!("device `{node}` is not listed in the input"))
+}
+
+fn parse(input: &str) -> Result<HashMap<&str, Vec<&str>>, ParseError> {
     let mut mapping = HashMap::new();
 
-    input.lines().for_each(|line| {
-        let (left, right) = line.split_once(": ").unwrap();
+    for line in parse_lines(input, |line| split_pair(line, ": ")) {
+        let (left, right) = line?;
         let nodes = right.split_ascii_whitespace().collect::<Vec<&str>>();
         mapping.insert(left, nodes);
-    });
+    }
 
-    mapping
+    Ok(mapping)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let network = parse(input);
+pub fn part_one(input: &str) -> Result<u64, String> {
+    let network = parse(input).map_err(|e| e.to_string())?;
 
     let mut queue = VecDeque::new();
 
-    let you = network.get("you").unwrap();
+    let you = outputs(&network, "you")?;
 
     queue.extend(you);
 
@@ -55,23 +66,22 @@ pub fn part_one(input: &str) -> Option<u64> {
         if node == "out" {
             path_count += 1;
         } else {
-            let nodes = network.get(node).unwrap();
+            let nodes = outputs(&network, node)?;
             for next in nodes {
                 queue.push_front(next);
             }
         }
     }
 
-    Some(path_count)
+    Ok(path_count)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let network = parse(input);
+pub fn part_two(input: &str) -> Result<u64, String> {
+    let network = parse(input).map_err(|e| e.to_string())?;
 
     let mut cache = HashMap::new();
 
-    let total = path_count(&mut cache, &network, "svr", false, false);
-    Some(total)
+    path_count(&mut cache, &network, "svr", false, false)
 }
 
 #[cfg(test)]
@@ -81,7 +91,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
+        assert_eq!(result, Ok(5));
     }
 
     #[test]
@@ -89,6 +99,6 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file_part(
             "examples", DAY, 2,
         ));
-        assert_eq!(result, Some(2));
+        assert_eq!(result, Ok(2));
     }
 }
@@ -1,4 +1,6 @@
-advent_of_code::solution!(12);
+advent_of_code::solution!(12, parse = parse);
+
+use advent_of_code::parse::{ParseError, integers, parse_lines, sections, split_pair, tuple};
 
 #[derive(Debug, Clone)]
 struct Region {
@@ -7,31 +9,28 @@ struct Region {
     presents: Vec<u64>,
 }
 
-fn parse(input: &str) -> Vec<Region> {
-    let line_split = input.split("\n\n");
-    let regions = line_split.last().unwrap();
+fn parse(input: &str) -> Result<Vec<Region>, ParseError> {
+    let regions = sections(input).last().unwrap_or("");
 
-    regions
-        .lines()
-        .map(|line| {
-            let (left, right) = line.split_once(": ").unwrap();
-            let (width, height) = left.split_once('x').unwrap();
-            let presents = right
-                .split_ascii_whitespace()
-                .map(|num| num.parse::<u64>().unwrap())
-                .collect();
+    parse_lines(regions, |line| {
+        let (size, presents) = split_pair(line, ": ")?;
+        let (width, height) = tuple(size, "x").map_err(|e| e.within(line))?;
+        let presents = integers(presents)
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.within(line))?;
 
-            Region {
-                width: width.parse().unwrap(),
-                height: height.parse().unwrap(),
-                presents,
-            }
+        Ok(Region {
+            width,
+            height,
+            presents,
         })
-        .collect()
+    })
+    .collect::<Result<_, _>>()
+    .map_err(|e| e.within(input))
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let regions = parse(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let regions = parse(input)?;
 
     let total = regions
         .iter()
@@ -45,7 +44,7 @@ pub fn part_one(input: &str) -> Option<u64> {
             if sub_value * 9 <= area { 1 } else { 0 }
         })
         .sum();
-    Some(total)
+    Ok(total)
 }
 
 pub fn part_two(_: &str) -> Option<u64> {
@@ -59,7 +58,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+        assert_eq!(result, Ok(3));
     }
 
     #[test]
@@ -0,0 +1,188 @@
+/// A union-find structure that partitions the elements `0..len` into disjoint components, which can be merged.
+/// Useful for puzzles about connectivity, e.g. circuits or regions.
+///
+/// Uses path compression and union by size, so merging and looking up components takes nearly constant time.
+///
+/// ```
+/// # use advent_of_code::disjoint_set::DisjointSet;
+/// let mut set = DisjointSet::new(4);
+/// set.union(0, 1);
+/// set.union(2, 1);
+/// assert_eq!(set.connected(0, 2), true);
+/// assert_eq!(set.component_count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    /// Size of the component of each root. Entries of elements that are not roots are outdated.
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Creates a set of `len` elements that are each in a component of their own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The representative of the component of `x`. Shortens the path to it for later lookups.
+    ///
+    /// # Panics
+    /// Panics if `x` is not an element of the set.
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+
+        let mut current = x;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Looks up the representative of the component of `x` without shortening the path to it.
+    fn root(&self, mut x: usize) -> usize {
+        while self.parents[x] != x {
+            x = self.parents[x];
+        }
+        x
+    }
+
+    /// Merges the components of `a` and `b`. Returns `false` if they were connected already.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // NOTE: attaching the smaller component to the larger one keeps the paths short.
+        let (root, child) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        self.components -= 1;
+        true
+    }
+
+    /// Whether `a` and `b` are in the same component.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the component of `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// The number of components.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Iterates over the sizes of all components, in order of their representatives.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|(x, parent)| x == *parent)
+            .map(|(x, _)| self.sizes[x])
+    }
+
+    /// Iterates over the elements of all components, ordered by their smallest element.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut components: Vec<Vec<usize>> = vec![];
+        // index of the component of each root in `components`.
+        let mut indices = vec![None; self.len()];
+
+        for x in 0..self.len() {
+            let root = self.root(x);
+            let index = *indices[root].get_or_insert_with(|| {
+                components.push(Vec::with_capacity(self.sizes[root]));
+                components.len() - 1
+            });
+            components[index].push(x);
+        }
+
+        components.into_iter()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DisjointSet;
+
+    #[test]
+    fn starts_disconnected() {
+        let mut set = DisjointSet::new(3);
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.component_count(), 3);
+        assert_eq!(set.connected(0, 1), false);
+        assert_eq!(set.size_of(2), 1);
+        assert_eq!(DisjointSet::new(0).is_empty(), true);
+    }
+
+    #[test]
+    fn merges_components() {
+        let mut set = DisjointSet::new(6);
+
+        assert_eq!(set.union(0, 1), true);
+        assert_eq!(set.union(2, 3), true);
+        assert_eq!(set.union(1, 3), true);
+        assert_eq!(set.union(0, 2), false);
+
+        assert_eq!(set.connected(0, 3), true);
+        assert_eq!(set.connected(0, 4), false);
+        assert_eq!(set.size_of(3), 4);
+        assert_eq!(set.component_count(), 3);
+    }
+
+    #[test]
+    fn lists_components() {
+        let mut set = DisjointSet::new(5);
+        set.union(4, 1);
+        set.union(3, 0);
+        set.union(1, 3);
+
+        let mut sizes: Vec<usize> = set.component_sizes().collect();
+        sizes.sort_unstable();
+
+        assert_eq!(sizes, vec![1, 4]);
+        assert_eq!(
+            set.components().collect::<Vec<_>>(),
+            vec![vec![0, 1, 3, 4], vec![2]]
+        );
+    }
+
+    #[test]
+    fn compresses_paths() {
+        let mut set = DisjointSet::new(4);
+        // merging two pairs leaves 3 two links below the root.
+        set.union(0, 1);
+        set.union(2, 3);
+        set.union(0, 2);
+
+        let root = set.find(3);
+        assert_eq!(set.parents[3], root);
+    }
+}
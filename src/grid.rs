@@ -0,0 +1,435 @@
+/// A two-dimensional grid of cells, as found in many puzzle inputs.
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the orthogonal neighbours of a cell, clockwise from the top.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours of a cell, clockwise from the top.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row. Positions are `(x, y)` with `(0, 0)` in the top left corner.
+///
+/// ```
+/// # use advent_of_code::grid::Grid;
+/// let grid = Grid::parse("#.\n.#", |b| b == b'#').unwrap();
+/// assert_eq!(grid[(1, 1)], true);
+/// assert_eq!(grid.neighbours8(0, 0).filter(|(_, cell)| **cell).count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `width` by `height` cells, computing each cell from its position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let (width, height) = if width == 0 || height == 0 {
+            (0, 0)
+        } else {
+            (width, height)
+        };
+
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid from text, mapping every byte of a line to a cell.
+    /// Lines may end with `\n` or `\r\n`, trailing line breaks are ignored.
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> T) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+
+        for (i, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            if i == 0 {
+                width = line.len();
+            } else if line.len() != width {
+                return Err(ParseGridError {
+                    line: i + 1,
+                    width: line.len(),
+                    expected: width,
+                });
+            }
+
+            cells.extend(line.bytes().map(&mut cell));
+            height += 1;
+        }
+
+        if width == 0 {
+            height = 0;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cells of the grid, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Whether `(x, y)` is a position in the grid. Takes signed values, so offsets can be checked before applying them.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` if it is outside the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)`, or `None` if it is outside the grid.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)` of a grid that repeats infinitely in every direction.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// Iterates over all positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells of the grid with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell that matches `predicate`, searching row by row.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    /// Iterates over the orthogonal neighbours of `(x, y)` that are inside the grid, with their positions.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of `(x, y)` that are inside the grid, with their positions.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = (x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?);
+            self.get(x, y).map(|cell| ((x, y), cell))
+        })
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    /// Panics if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterates over the cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).filter_map(move |y| self.get(x, y))
+    }
+
+    /// Iterates over the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // NOTE: `chunks` panics for a size of zero, which empty grids have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over the columns of the grid, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Iterates over the diagonals running from the top left to the bottom right,
+    /// starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+
+        starts.map(move |(x, y)| (0..).map_while(move |i| self.get(x + i, y + i)))
+    }
+
+    /// Iterates over the diagonals running from the top right to the bottom left,
+    /// starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..(self.width + self.height).saturating_sub(1)).map(move |sum| {
+            let x = sum.min(self.width - 1);
+            (0..).map_while(move |i| self.get(x.checked_sub(i)?, sum - x + i))
+        })
+    }
+
+    /// Creates a grid of the same size by mapping every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of `width` by `height` cells that all equal `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_fn(width, height, |_, _| value.clone())
+    }
+
+    /// Mirrors the grid along the diagonal from the top left to the bottom right, turning rows into columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid by a quarter turn clockwise, so the top row becomes the right column.
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid by a quarter turn counter-clockwise, so the top row becomes the left column.
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let width = self.width;
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "position ({x}, {y}) is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| {
+            panic!("position ({x}, {y}) is outside of the {width}x{height} grid")
+        })
+    }
+}
+
+/// Displays the grid row by row, without a trailing line break.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing a [`Grid`] whose lines differ in length.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGridError {
+    /// The line that differs in length from the first one, starting at 1.
+    pub line: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl Error for ParseGridError {}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} of the grid is {} cells wide, expected {} like the first line",
+            self.line, self.width, self.expected
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, ParseGridError};
+
+    fn digits() -> Grid<u8> {
+        Grid::parse("123\n456\n", |b| b - b'0').unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a u8>>) -> Vec<Vec<u8>> {
+        lines.map(|line| line.copied().collect()).collect()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = digits();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(Grid::parse("12\r\n34\r\n", |b| b).unwrap().width(), 2);
+        assert_eq!(Grid::parse("", |b| b).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn rejects_ragged_grids() {
+        assert_eq!(
+            Grid::parse("123\n45\n", |b| b),
+            Err(ParseGridError {
+                line: 2,
+                width: 2,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn accesses_cells() {
+        let mut grid = digits();
+
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.contains(-1, 0), false);
+        assert_eq!(grid.contains(2, 1), true);
+        assert_eq!(*grid.get_wrapping(-1, -1), 6);
+        assert_eq!(*grid.get_wrapping(4, 2), 2);
+        assert_eq!(grid.position(|cell| *cell == 5), Some((1, 1)));
+
+        grid[(0, 1)] = 9;
+        assert_eq!(grid.row(1), &[9, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_positions_outside_of_the_grid() {
+        let _ = digits()[(3, 0)];
+    }
+
+    #[test]
+    fn iterates_over_neighbours() {
+        let grid = digits();
+        let values = |n: Vec<((usize, usize), &u8)>| n.iter().map(|(_, v)| **v).collect::<Vec<_>>();
+
+        assert_eq!(values(grid.neighbours4(0, 0).collect()), vec![2, 4]);
+        assert_eq!(values(grid.neighbours4(1, 1).collect()), vec![2, 6, 4]);
+        assert_eq!(values(grid.neighbours8(0, 0).collect()), vec![2, 5, 4]);
+        assert_eq!(
+            values(grid.neighbours8(1, 0).collect()),
+            vec![3, 6, 5, 4, 1]
+        );
+        assert_eq!(grid.neighbours8(2, 1).next(), Some(((2, 0), &3)));
+    }
+
+    #[test]
+    fn iterates_over_lines() {
+        let grid = digits();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(
+            collect(grid.columns()),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            collect(grid.diagonals()),
+            vec![vec![4], vec![1, 5], vec![2, 6], vec![3]]
+        );
+        assert_eq!(
+            collect(grid.anti_diagonals()),
+            vec![vec![1], vec![2, 4], vec![3, 5], vec![6]]
+        );
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = digits();
+
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        assert_eq!(grid.map(|cell| cell * 2).to_string(), "246\n81012");
+    }
+
+    #[test]
+    fn creates_grids() {
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..");
+        assert_eq!(Grid::from_fn(2, 2, |x, y| x + y).to_string(), "01\n12");
+        assert_eq!(Grid::new(0, 3, '.').height(), 0);
+    }
+}
@@ -0,0 +1,322 @@
+/// A set of integers stored as sorted, disjoint ranges, for puzzles about large ranges of ids or coordinates.
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// Integer types that an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + Debug {
+    /// The next larger value, or `None` for the largest value of the type.
+    fn successor(self) -> Option<Self>;
+    /// The next smaller value, or `None` for the smallest value of the type.
+    fn predecessor(self) -> Option<Self>;
+    /// The number of values in `start..=end`, saturating at [`u64::MAX`].
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u64 {
+                    let count = end as i128 - start as i128 + 1;
+                    u64::try_from(count.max(0)).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers, stored as sorted inclusive ranges. Overlapping and adjacent ranges are merged on insertion,
+/// so lookups take a binary search and the ranges can be read back in normalised form.
+///
+/// ```
+/// # use advent_of_code::interval_set::IntervalSet;
+/// let set: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+/// assert_eq!(set.ranges().collect::<Vec<_>>(), vec![3..=5, 10..=20]);
+/// assert_eq!(set.contains(11), true);
+/// assert_eq!(set.len(), 14);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, disjoint and non-adjacent inclusive ranges as `(start, end)`.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set from ranges in any order, merging them in a single pass after sorting.
+    fn from_unsorted(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if last.1.successor().is_none_or(|next| start <= next) => {
+                    last.1 = last.1.max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    /// Adds all values of `range` to the set. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // ranges that end at least one value before `start` stay as they are, like ranges that start
+        // at least one value after `end`. Everything in between touches the new range.
+        let first = self
+            .ranges
+            .partition_point(|(_, e)| e.successor().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|(s, _)| s.predecessor().is_none_or(|prev| prev <= end));
+
+        let merged = if first < last {
+            (
+                start.min(self.ranges[first].0),
+                end.max(self.ranges[last - 1].1),
+            )
+        } else {
+            (start, end)
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Index of the first range that does not end before `value`.
+    fn index_of(&self, value: T) -> usize {
+        self.ranges.partition_point(|(_, end)| *end < value)
+    }
+
+    /// Whether `value` is in the set.
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .get(self.index_of(value))
+            .is_some_and(|(start, _)| *start <= value)
+    }
+
+    /// Whether every value of `range` is in the set. Holds for empty ranges.
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return true;
+        }
+
+        self.ranges
+            .get(self.index_of(start))
+            .is_some_and(|(s, e)| *s <= start && end <= *e)
+    }
+
+    /// Whether any value of `range` is in the set.
+    pub fn intersects(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return false;
+        }
+
+        self.ranges
+            .get(self.index_of(start))
+            .is_some_and(|(s, _)| *s <= end)
+    }
+
+    /// The values that are in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_unsorted([self.ranges.as_slice(), other.ranges.as_slice()].concat())
+    }
+
+    /// The values that are in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            // the range that ends first can not intersect any later range of the other set.
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The values that are in this set but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for &(start, end) in &self.ranges {
+            while other.ranges.get(j).is_some_and(|(_, e)| *e < start) {
+                j += 1;
+            }
+
+            // the start of the part of the range that is not removed yet, if any is left.
+            let mut remaining = Some(start);
+
+            for &(s, e) in other.ranges[j..].iter().take_while(|(s, _)| *s <= end) {
+                let Some(current) = remaining else { break };
+
+                if s > current {
+                    // NOTE: `s` is larger than another value, so it has a predecessor.
+                    ranges.push((current, s.predecessor().unwrap()));
+                }
+                remaining = e.successor().filter(|next| *next <= end);
+            }
+
+            if let Some(current) = remaining {
+                ranges.push((current, end));
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The number of values in the set, saturating at [`u64::MAX`].
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().fold(0, |total: u64, (start, end)| {
+            total.saturating_add(T::count(*start, *end))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Iterates over the normalised ranges of the set in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().map(|(start, end)| *start..=*end)
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().map(RangeInclusive::into_inner).collect())
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        let added = iter.into_iter().map(RangeInclusive::into_inner);
+        *self = Self::from_unsorted(self.ranges.iter().copied().chain(added).collect());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use super::IntervalSet;
+
+    fn set(ranges: &[RangeInclusive<u64>]) -> IntervalSet<u64> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<u64>) -> Vec<RangeInclusive<u64>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn normalises_ranges() {
+        assert_eq!(
+            ranges(&set(&[10..=14, 3..=5, 16..=20, 12..=18])),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(ranges(&set(&[1..=2, 3..=4, 6..=6])), vec![1..=4, 6..=6]);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = set(&[5..=4]);
+        assert_eq!(empty.is_empty(), true);
+    }
+
+    #[test]
+    fn inserts_ranges() {
+        let mut set = set(&[1..=2, 6..=8, 12..=14]);
+
+        set.insert(4..=4);
+        assert_eq!(ranges(&set), vec![1..=2, 4..=4, 6..=8, 12..=14]);
+
+        set.insert(3..=9);
+        assert_eq!(ranges(&set), vec![1..=9, 12..=14]);
+
+        set.insert(11..=11);
+        set.insert(20..=u64::MAX);
+        assert_eq!(ranges(&set), vec![1..=9, 11..=14, 20..=u64::MAX]);
+
+        set.insert(0..=0);
+        assert_eq!(ranges(&set)[0], 0..=9);
+    }
+
+    #[test]
+    fn checks_membership() {
+        let set = set(&[3..=5, 10..=20]);
+
+        assert_eq!(set.contains(2), false);
+        assert_eq!(set.contains(3), true);
+        assert_eq!(set.contains(20), true);
+        assert_eq!(set.contains(21), false);
+        assert_eq!(set.contains_range(11..=19), true);
+        assert_eq!(set.contains_range(4..=10), false);
+        assert_eq!(set.intersects(6..=9), false);
+        assert_eq!(set.intersects(6..=10), true);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[1..=10, 20..=30]);
+        let b = set(&[5..=8, 10..=22, 30..=40]);
+
+        assert_eq!(ranges(&a.union(&b)), vec![1..=40]);
+        assert_eq!(
+            ranges(&a.intersection(&b)),
+            vec![5..=8, 10..=10, 20..=22, 30..=30]
+        );
+        assert_eq!(ranges(&a.difference(&b)), vec![1..=4, 9..=9, 23..=29]);
+        assert_eq!(ranges(&b.difference(&a)), vec![11..=19, 31..=40]);
+        assert_eq!(a.difference(&a).is_empty(), true);
+    }
+
+    #[test]
+    fn counts_values() {
+        assert_eq!(set(&[3..=5, 10..=20]).len(), 14);
+        assert_eq!(set(&[0..=u64::MAX]).len(), u64::MAX);
+
+        let signed: IntervalSet<i32> = [-5..=5].into_iter().collect();
+        assert_eq!(signed.len(), 11);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod disjoint_set;
+pub mod grid;
+pub mod interval_set;
+pub mod parse;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
@@ -1,14 +1,20 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time, verify};
+use advent_of_code::template::runner::RunOptions;
 use args::{AppArguments, parse};
+use solutions::SOLUTIONS;
 
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{Day, Timeouts, Year, input::InputSource, stats::Statistic};
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -21,20 +27,44 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            perf: bool,
+            watch: bool,
+            input: InputSource,
         },
         All {
             release: bool,
+            isolated: bool,
+            timeouts: Timeouts,
+            perf: bool,
+            year: Option<Year>,
+        },
+        Verify {
+            day: Option<Day>,
+            year: Option<Year>,
+            release: bool,
+            isolated: bool,
+            all_inputs: bool,
         },
         Time {
             all: bool,
             day: Option<Day>,
+            year: Option<Year>,
             store: bool,
+            isolated: bool,
+            warmup: u32,
+            statistic: Statistic,
+            compare: bool,
+            threshold: f64,
+            history: Option<Day>,
+            timeouts: Timeouts,
+            perf: bool,
         },
         #[cfg(feature = "today")]
         Today,
@@ -46,15 +76,44 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                timeouts: parse_timeouts(&mut args)?,
+                perf: args.contains("--perf"),
+                year: args.opt_value_from_str("--year")?,
+            },
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                all_inputs: args.contains("--all-inputs"),
+                year: args.opt_value_from_str("--year")?,
+                day: args.opt_free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let isolated = args.contains("--isolated");
+                let warmup = args.opt_value_from_str("--warmup")?.unwrap_or_default();
+                let statistic = args.opt_value_from_str("--stat")?.unwrap_or_default();
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let history = args.opt_value_from_str("--history")?;
+                let timeouts = parse_timeouts(&mut args)?;
+                let perf = args.contains("--perf");
+                let year = args.opt_value_from_str("--year")?;
 
                 AppArguments::Time {
                     all,
+                    year,
                     day: args.opt_free_from_str()?,
                     store,
+                    isolated,
+                    warmup,
+                    statistic,
+                    compare,
+                    threshold,
+                    history,
+                    timeouts,
+                    perf,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -67,13 +126,41 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let perf = args.contains("--perf");
+                let watch = args.contains("--watch");
+                let stdin = args.contains("-");
+                let path = args.opt_value_from_str("--input")?;
+                let example = args.contains("--example");
+                let day = args.free_from_str()?;
+
+                // NOTE: the part of `--example` is optional, so it is read once all other arguments are.
+                let input = match (stdin, path, example) {
+                    (false, None, false) => InputSource::Puzzle,
+                    (true, None, false) => InputSource::Stdin,
+                    (false, Some(path), false) => InputSource::Path(path),
+                    (false, None, true) => InputSource::Example(args.opt_free_from_str()?),
+                    _ => {
+                        eprintln!("Only one of `--input`, `--example` and `-` can be used.");
+                        process::exit(1);
+                    }
+                };
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    submit,
+                    dhat,
+                    perf,
+                    watch,
+                    input,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -93,6 +180,20 @@ mod args {
 
         Ok(app_args)
     }
+
+    fn parse_timeouts(args: &mut pico_args::Arguments) -> Result<Timeouts, pico_args::Error> {
+        Ok(Timeouts {
+            day: args.opt_value_from_fn("--timeout", parse_seconds)?,
+            total: args.opt_value_from_fn("--total-timeout", parse_seconds)?,
+        })
+    }
+
+    fn parse_seconds(s: &str) -> Result<Duration, String> {
+        s.parse::<f64>()
+            .ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .ok_or_else(|| format!("expected a number of seconds, got `{s}`"))
+    }
 }
 
 fn main() {
@@ -102,38 +203,99 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                release,
+                isolated,
+                timeouts,
+                perf,
+                year,
+            } => all::handle(SOLUTIONS, year, release, isolated, timeouts, perf),
+            AppArguments::Time {
+                day,
+                year,
+                all,
+                store,
+                isolated,
+                warmup,
+                statistic,
+                compare,
+                threshold,
+                history: None,
+                timeouts,
+                perf,
+            } => time::handle(
+                SOLUTIONS,
+                day,
+                year,
+                all,
+                store,
+                isolated,
+                timeouts,
+                RunOptions {
+                    is_timed: true,
+                    warmup,
+                    statistic,
+                    perf,
+                    quiet: false,
+                },
+                compare.then_some(threshold),
+            ),
+            AppArguments::Time {
+                history: Some(day), ..
+            } => time::handle_history(day),
+            AppArguments::Verify {
+                day,
+                year,
+                release,
+                isolated,
+                all_inputs,
+            } => verify::handle(SOLUTIONS, day, year, release, isolated, all_inputs),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // NOTE: downloaded first, so the template can be filled with the puzzle's title and examples.
                 if download {
                     download::handle(day);
                 }
+                scaffold::handle(day, overwrite, template.as_deref());
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                perf,
+                watch,
+                input,
+            } => {
+                if !watch {
+                    solve::handle(day, release, dhat, submit, perf, &input);
+                } else if dhat || submit.is_some() || !input.is_puzzle() {
+                    eprintln!(
+                        "`--watch` can not be combined with `--dhat`, `--submit` or another input."
+                    );
+                    std::process::exit(1);
+                } else {
+                    solve::watch(day, release, perf);
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
                         download::handle(day);
+                        scaffold::handle(day, false, None);
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during the days of the event \
+                            in december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
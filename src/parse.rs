@@ -0,0 +1,611 @@
+/// Parsers for common shapes of puzzle input that borrow from the input instead of allocating,
+/// and report the line and column of malformed input instead of panicking.
+use std::error::Error;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use crate::grid::Grid;
+
+/// What went wrong at the position of a [`ParseError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A digit was expected, but another character or the end of the input was found.
+    ExpectedDigit { found: Option<char> },
+    /// Something else than the `expected` token was found, or the end of the input.
+    ExpectedToken {
+        expected: &'static str,
+        found: Option<String>,
+    },
+    /// A number does not fit into the integer type it is parsed as.
+    Overflow,
+    /// A line has another number of fields than expected.
+    FieldCount { expected: usize, found: usize },
+    /// A line of a grid differs in length from the first line.
+    LineLength { expected: usize, found: usize },
+}
+
+/// An error of parsing input, located by its line and column (both starting at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+    /// Byte offset of the error in the parsed input.
+    offset: usize,
+    /// Address of the parsed input, to locate the error in an input that contains it.
+    origin: usize,
+}
+
+impl ParseError {
+    /// Creates an error at byte `offset` of `input`, e.g. for checks that a day does on top of the parsers here.
+    pub fn new(input: &str, offset: usize, kind: ParseErrorKind) -> Self {
+        let before = &input.as_bytes()[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1);
+
+        Self {
+            line: before.iter().filter(|b| **b == b'\n').count() + 1,
+            // NOTE: counts characters instead of bytes, by skipping UTF-8 continuation bytes.
+            column: before[line_start..]
+                .iter()
+                .filter(|b| (**b as i8) >= -0x40)
+                .count()
+                + 1,
+            kind,
+            offset,
+            origin: input.as_ptr() as usize,
+        }
+    }
+
+    /// Locates the error in `input`, if the text it occurred in is part of `input`. Used to report positions
+    /// in the whole puzzle input when parsing a line or section of it.
+    ///
+    /// ```
+    /// # use advent_of_code::parse::integer;
+    /// let input = "1\n2x\n3";
+    /// let line = input.lines().nth(1).unwrap();
+    /// let error = integer::<u32>(line).unwrap_err().within(input);
+    /// assert_eq!((error.line, error.column), (2, 2));
+    /// ```
+    #[must_use]
+    pub fn within(self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+
+        if self.origin < start || self.origin + self.offset > start + input.len() {
+            return self;
+        }
+
+        Self::new(input, self.origin - start + self.offset, self.kind)
+    }
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+
+        match &self.kind {
+            ParseErrorKind::ExpectedDigit { found: Some(c) } => {
+                write!(f, "expected a digit, found `{}`", c.escape_debug())
+            }
+            ParseErrorKind::ExpectedDigit { found: None } => {
+                write!(f, "expected a digit, found the end of the input")
+            }
+            ParseErrorKind::ExpectedToken {
+                expected,
+                found: Some(token),
+            } => write!(f, "expected {expected}, found `{}`", token.escape_debug()),
+            ParseErrorKind::ExpectedToken {
+                expected,
+                found: None,
+            } => write!(f, "expected {expected}, found the end of the input"),
+            ParseErrorKind::Overflow => write!(f, "number is too large for its type"),
+            ParseErrorKind::FieldCount { expected, found } => {
+                write!(f, "expected {expected} fields, found {found}")
+            }
+            ParseErrorKind::LineLength { expected, found } => {
+                write!(
+                    f,
+                    "line is {found} characters long, expected {expected} like the first line"
+                )
+            }
+        }
+    }
+}
+
+fn expected_digit(input: &str, offset: usize) -> ParseError {
+    let found = input.get(offset..).and_then(|rest| rest.chars().next());
+    ParseError::new(input, offset, ParseErrorKind::ExpectedDigit { found })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Integer types that can be parsed from decimal digits.
+pub trait FromDigits: Copy {
+    /// Whether the type can hold negative numbers, which are written with a leading `-`.
+    const SIGNED: bool;
+    const ZERO: Self;
+
+    /// Appends a decimal digit to the number, away from zero. Returns `None` on overflow.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_from_digits {
+    ($($t:ty),*) => {
+        $(
+            impl FromDigits for $t {
+                const SIGNED: bool = <$t>::MIN != 0;
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(10)?;
+                    if negative {
+                        shifted.checked_sub(digit as Self)
+                    } else {
+                        shifted.checked_add(digit as Self)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_digits!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Parses the integer that starts at `start`, returning it with the offset of the byte after it.
+#[inline]
+fn integer_at<T: FromDigits>(input: &str, start: usize) -> Result<(T, usize), ParseError> {
+    let bytes = input.as_bytes();
+    let negative = T::SIGNED && bytes.get(start) == Some(&b'-');
+    let digits_start = start + usize::from(negative);
+
+    let mut value = T::ZERO;
+    let mut i = digits_start;
+
+    while let Some(digit) = bytes.get(i).filter(|b| b.is_ascii_digit()) {
+        value = value
+            .push_digit(digit - b'0', negative)
+            .ok_or_else(|| ParseError::new(input, start, ParseErrorKind::Overflow))?;
+        i += 1;
+    }
+
+    if i == digits_start {
+        return Err(expected_digit(input, i));
+    }
+
+    Ok((value, i))
+}
+
+/// Parses `input` as a decimal integer, with a leading `-` for negative numbers of signed types.
+///
+/// ```
+/// # use advent_of_code::parse::integer;
+/// assert_eq!(integer::<i32>("-42"), Ok(-42));
+/// assert_eq!(integer::<u8>("256").is_err(), true);
+/// ```
+pub fn integer<T: FromDigits>(input: &str) -> Result<T, ParseError> {
+    let (value, end) = integer_at(input, 0)?;
+
+    if end != input.len() {
+        return Err(expected_digit(input, end));
+    }
+
+    Ok(value)
+}
+
+/// Iterates over all integers in `input`, skipping any text around them.
+///
+/// A `-` right before a number makes it negative if `T` is signed, so ranges like `3-5` should be parsed as an
+/// unsigned type.
+///
+/// ```
+/// # use advent_of_code::parse::integers;
+/// let numbers: Result<Vec<i64>, _> = integers("p=0,4 v=3,-3").collect();
+/// assert_eq!(numbers, Ok(vec![0, 4, 3, -3]));
+/// ```
+pub fn integers<T: FromDigits>(input: &str) -> Integers<'_, T> {
+    Integers {
+        input,
+        offset: 0,
+        marker: PhantomData,
+    }
+}
+
+/// Iterator over the integers of a text, see [`integers`].
+pub struct Integers<'a, T> {
+    input: &'a str,
+    offset: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: FromDigits> Iterator for Integers<'_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_bytes();
+
+        let starts_number = |i: usize| {
+            bytes[i].is_ascii_digit()
+                || (T::SIGNED
+                    && bytes[i] == b'-'
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+        };
+
+        let Some(start) = (self.offset..bytes.len()).find(|i| starts_number(*i)) else {
+            self.offset = bytes.len();
+            return None;
+        };
+
+        match integer_at(self.input, start) {
+            Ok((value, end)) => {
+                self.offset = end;
+                Some(Ok(value))
+            }
+            Err(e) => {
+                // skip the rest of the number that did not fit, so the next call continues after it.
+                self.offset = (start + 1..bytes.len())
+                    .find(|i| !bytes[*i].is_ascii_digit())
+                    .unwrap_or(bytes.len());
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Tuples of integers that can be parsed by [`tuple`].
+pub trait FromFields: Sized {
+    /// The number of fields of the tuple.
+    const FIELDS: usize;
+
+    /// Parses the tuple from exactly [`FromFields::FIELDS`] fields of `input`.
+    fn from_fields<'a>(
+        input: &'a str,
+        fields: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_fields {
+    ($fields:literal: $($t:ident),*) => {
+        impl<$($t: FromDigits),*> FromFields for ($($t,)*) {
+            const FIELDS: usize = $fields;
+
+            fn from_fields<'a>(
+                input: &'a str,
+                mut fields: impl Iterator<Item = &'a str>,
+            ) -> Result<Self, ParseError> {
+                Ok(($(
+                    integer::<$t>(fields.next().unwrap_or(&input[input.len()..]))
+                        .map_err(|e| e.within(input))?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_from_fields!(2: A, B);
+impl_from_fields!(3: A, B, C);
+impl_from_fields!(4: A, B, C, D);
+impl_from_fields!(5: A, B, C, D, E);
+
+/// Parses a fixed number of integers separated by `separator`.
+///
+/// ```
+/// # use advent_of_code::parse::tuple;
+/// assert_eq!(tuple::<(i64, i64, i64)>("162,-817,812", ","), Ok((162, -817, 812)));
+/// assert_eq!(tuple::<(u64, u64)>("3-5", "-"), Ok((3, 5)));
+/// ```
+pub fn tuple<T: FromFields>(input: &str, separator: &str) -> Result<T, ParseError> {
+    let found = input.split(separator).count();
+
+    if found != T::FIELDS {
+        // point at the separator of the first field too many, or at the end if fields are missing.
+        let offset = input
+            .match_indices(separator)
+            .nth(T::FIELDS - 1)
+            .map_or(input.len(), |(i, _)| i);
+
+        return Err(ParseError::new(
+            input,
+            offset,
+            ParseErrorKind::FieldCount {
+                expected: T::FIELDS,
+                found,
+            },
+        ));
+    }
+
+    T::from_fields(input, input.split(separator))
+}
+
+/// Splits `input` at the first `separator`, like [`str::split_once`], but fails with a [`ParseError`].
+pub fn split_pair<'a>(input: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    input.split_once(separator).ok_or_else(|| {
+        ParseError::new(
+            input,
+            input.len(),
+            ParseErrorKind::FieldCount {
+                expected: 2,
+                found: 1,
+            },
+        )
+    })
+}
+
+/// Parses every line of `input` with `parse`, locating errors in `input`.
+///
+/// ```
+/// # use advent_of_code::parse::{integer, parse_lines};
+/// let numbers: Result<Vec<u32>, _> = parse_lines("1\n2\n3", integer).collect();
+/// assert_eq!(numbers, Ok(vec![1, 2, 3]));
+/// ```
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> impl Iterator<Item = Result<T, ParseError>> {
+    input
+        .lines()
+        .map(move |line| parse(line).map_err(|e| e.within(input)))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Iterates over the sections of `input` that are separated by blank lines, without their trailing line break.
+///
+/// ```
+/// # use advent_of_code::parse::sections;
+/// let sections: Vec<&str> = sections("3-5\n10-14\n\n1\n5\n").collect();
+/// assert_eq!(sections, vec!["3-5\n10-14", "1\n5"]);
+/// ```
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections { input, offset: 0 }
+}
+
+/// Iterator over the sections of a text, see [`sections`].
+pub struct Sections<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl Sections<'_> {
+    /// The line at the current offset, without its line break, and the offset of the next line.
+    fn line(&self) -> Option<(&str, usize)> {
+        let rest = self
+            .input
+            .get(self.offset..)
+            .filter(|rest| !rest.is_empty())?;
+
+        Some(match rest.find('\n') {
+            Some(end) => (&rest[..end], self.offset + end + 1),
+            None => (rest, self.input.len()),
+        })
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_blank = |line: &str| line.trim_end_matches('\r').is_empty();
+
+        while let Some((line, next)) = self.line() {
+            if !is_blank(line) {
+                break;
+            }
+            self.offset = next;
+        }
+
+        let start = self.offset;
+        let mut end = None;
+
+        while let Some((line, next)) = self.line() {
+            if is_blank(line) {
+                break;
+            }
+            end = Some(self.offset + line.trim_end_matches('\r').len());
+            self.offset = next;
+        }
+
+        end.map(|end| &self.input[start..end])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses lines of decimal digits into a grid of their values.
+///
+/// ```
+/// # use advent_of_code::parse::digit_grid;
+/// let grid = digit_grid("987\n811\n").unwrap();
+/// assert_eq!(grid[(0, 1)], 8);
+/// ```
+pub fn digit_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    let bytes = input.as_bytes();
+    // NOTE: `\r` is only part of a line break, i.e. before `\n` or at the end of the input.
+    let is_line_break = |i: usize| match bytes[i] {
+        b'\n' => true,
+        b'\r' => matches!(bytes.get(i + 1), None | Some(b'\n')),
+        _ => false,
+    };
+
+    if let Some(offset) =
+        (0..bytes.len()).find(|&i| !bytes[i].is_ascii_digit() && !is_line_break(i))
+    {
+        return Err(expected_digit(input, offset));
+    }
+
+    Grid::parse(input, |b| b - b'0').map_err(|e| {
+        let line_start: usize = input
+            .split_inclusive('\n')
+            .take(e.line - 1)
+            .map(str::len)
+            .sum();
+
+        ParseError::new(
+            input,
+            line_start + e.width.min(e.expected),
+            ParseErrorKind::LineLength {
+                expected: e.expected,
+                found: e.width,
+            },
+        )
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        ParseError, ParseErrorKind, digit_grid, integer, integers, parse_lines, sections,
+        split_pair, tuple,
+    };
+
+    fn position(error: ParseError) -> (usize, usize, ParseErrorKind) {
+        (error.line, error.column, error.kind)
+    }
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(integer::<u64>("0"), Ok(0));
+        assert_eq!(integer::<i8>("-128"), Ok(-128));
+        assert_eq!(integer::<i64>("9223372036854775807"), Ok(i64::MAX));
+        assert_eq!(
+            position(integer::<u8>("256").unwrap_err()),
+            (1, 1, ParseErrorKind::Overflow)
+        );
+        assert_eq!(
+            position(integer::<u32>("-1").unwrap_err()),
+            (1, 1, ParseErrorKind::ExpectedDigit { found: Some('-') })
+        );
+        assert_eq!(
+            position(integer::<u32>("12a").unwrap_err()),
+            (1, 3, ParseErrorKind::ExpectedDigit { found: Some('a') })
+        );
+        assert_eq!(
+            position(integer::<u32>("").unwrap_err()),
+            (1, 1, ParseErrorKind::ExpectedDigit { found: None })
+        );
+    }
+
+    #[test]
+    fn finds_integers_in_text() {
+        let signed: Vec<i32> = integers("x=-3, y=10..-12").map(Result::unwrap).collect();
+        let unsigned: Vec<u32> = integers("3-5\n10-14").map(Result::unwrap).collect();
+
+        assert_eq!(signed, vec![-3, 10, -12]);
+        assert_eq!(unsigned, vec![3, 5, 10, 14]);
+        assert_eq!(integers::<u8>("a - b").next(), None);
+
+        let mut overflowing = integers::<u8>("1\n 300 4");
+        assert_eq!(overflowing.next(), Some(Ok(1)));
+        assert_eq!(
+            position(overflowing.next().unwrap().unwrap_err()),
+            (2, 2, ParseErrorKind::Overflow)
+        );
+        assert_eq!(overflowing.next(), Some(Ok(4)));
+    }
+
+    #[test]
+    fn parses_tuples() {
+        assert_eq!(tuple::<(i64, i64, i64)>("1,-2,3", ","), Ok((1, -2, 3)));
+        assert_eq!(tuple::<(u8, u64)>("4x5", "x"), Ok((4, 5)));
+        assert_eq!(
+            position(tuple::<(u8, u8)>("1,2,3", ",").unwrap_err()),
+            (
+                1,
+                4,
+                ParseErrorKind::FieldCount {
+                    expected: 2,
+                    found: 3
+                }
+            )
+        );
+        assert_eq!(
+            position(tuple::<(u8, u8, u8)>("1,2", ",").unwrap_err()),
+            (
+                1,
+                4,
+                ParseErrorKind::FieldCount {
+                    expected: 3,
+                    found: 2
+                }
+            )
+        );
+        assert_eq!(
+            position(tuple::<(u8, u8)>("1, 2", ",").unwrap_err()),
+            (1, 3, ParseErrorKind::ExpectedDigit { found: Some(' ') })
+        );
+        assert_eq!(split_pair("4x4: 1 2", ": "), Ok(("4x4", "1 2")));
+        assert_eq!(split_pair("4x4", ": ").is_err(), true);
+    }
+
+    #[test]
+    fn locates_errors_of_lines() {
+        let input = "1,2\n3,4\n5;6";
+        let pairs: Result<Vec<(u8, u8)>, _> = parse_lines(input, |line| tuple(line, ",")).collect();
+
+        assert_eq!(pairs.unwrap_err().line, 3);
+
+        let error = integer::<u8>("x").unwrap_err();
+        assert_eq!(error.clone().within(input), error);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a digit, found `x`"
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "a\nb\n\n\nc\r\n\r\nd\n";
+        let parts: Vec<&str> = sections(input).collect();
+
+        assert_eq!(parts, vec!["a\nb", "c", "d"]);
+        assert_eq!(sections("\n\n").next(), None);
+
+        let error = integer::<u8>(&parts[2][1..]).unwrap_err().within(input);
+        assert_eq!((error.line, error.column), (7, 2));
+    }
+
+    #[test]
+    fn parses_digit_grids() {
+        let grid = digit_grid("123\r\n456\r\n").unwrap();
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(
+            position(digit_grid("12\n3x").unwrap_err()),
+            (2, 2, ParseErrorKind::ExpectedDigit { found: Some('x') })
+        );
+        assert_eq!(
+            position(digit_grid("12\n3").unwrap_err()),
+            (
+                2,
+                2,
+                ParseErrorKind::LineLength {
+                    expected: 2,
+                    found: 1
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_carriage_returns_within_lines() {
+        assert_eq!(digit_grid("12\r").unwrap().row(0), &[1, 2]);
+        assert_eq!(
+            position(digit_grid("12\r\n3\r4\r\n").unwrap_err()),
+            (2, 2, ParseErrorKind::ExpectedDigit { found: Some('\r') })
+        );
+        assert_eq!(
+            position(digit_grid("12\r\r\n34").unwrap_err()),
+            (1, 3, ParseErrorKind::ExpectedDigit { found: Some('\r') })
+        );
+    }
+}
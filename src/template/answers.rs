@@ -0,0 +1,439 @@
+/// Ledger of submitted answers, used to avoid resubmitting answers that are known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::aoc_client::{Hint, Submission};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Version of the `answers.json` schema written by [`Answers::store_file`].
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Response of adventofcode.com to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Not checked, as an answer was submitted too recently.
+    RateLimited,
+}
+
+impl Verdict {
+    /// The verdict of a submission, if the answer was checked.
+    pub fn from_submission(value: &Submission) -> Option<Self> {
+        match value {
+            Submission::Correct => Some(Verdict::Correct),
+            Submission::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => Some(Verdict::TooHigh),
+            Submission::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => Some(Verdict::TooLow),
+            Submission::Incorrect { hint: None } => Some(Verdict::Wrong),
+            Submission::AlreadySolved => None,
+        }
+    }
+
+    #[must_use]
+    pub fn describe(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate limited",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate_limited",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate_limited" => Ok(Verdict::RateLimited),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp of the submission, in seconds.
+    pub timestamp: u64,
+}
+
+/// Outcome of checking an answer against the ledger before submitting it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check<'a> {
+    /// Not submitted before and within the known bounds.
+    New,
+    /// The part is solved already, with the contained answer.
+    Solved(&'a str),
+    /// The answer was submitted before and was wrong.
+    KnownWrong(Verdict),
+    /// The answer is not lower than an answer that was too high, or not higher than one that was too low.
+    OutOfBounds { verdict: Verdict, bound: &'a str },
+}
+
+/// All answers that were submitted, in order of submission.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Attempt>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns an empty ledger.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(contents) => Answers::try_from(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn attempts(&self, day: Day, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.data
+            .iter()
+            .filter(move |x| x.day == day && x.part == part)
+    }
+
+    /// The accepted answer of a part, if it was solved.
+    pub fn correct(&self, day: Day, part: u8) -> Option<&str> {
+        self.attempts(day, part)
+            .find(|x| x.verdict == Verdict::Correct)
+            .map(|x| x.answer.as_str())
+    }
+
+    /// Whether `answer` matches the accepted answer of a part. `None` if the part was not solved yet.
+    pub fn verify(&self, day: Day, part: u8, answer: Option<&str>) -> Option<bool> {
+        self.correct(day, part)
+            .map(|correct| answer.is_some_and(|answer| answer == correct))
+    }
+
+    /// Checks an answer against the previous submissions of its part.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Check<'_> {
+        if let Some(correct) = self.correct(day, part) {
+            return Check::Solved(correct);
+        }
+
+        let known_wrong = self
+            .attempts(day, part)
+            .find(|x| x.answer == answer && x.verdict != Verdict::RateLimited);
+
+        if let Some(attempt) = known_wrong {
+            return Check::KnownWrong(attempt.verdict);
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Check::New;
+        };
+
+        let numeric = |verdict: Verdict| {
+            self.attempts(day, part)
+                .filter(move |x| x.verdict == verdict)
+                .filter_map(|x| x.answer.parse::<i128>().ok().map(|value| (value, x)))
+        };
+
+        let too_high = numeric(Verdict::TooHigh).min_by_key(|(value, _)| *value);
+        if let Some((bound, attempt)) = too_high
+            && value >= bound
+        {
+            return Check::OutOfBounds {
+                verdict: Verdict::TooHigh,
+                bound: &attempt.answer,
+            };
+        }
+
+        let too_low = numeric(Verdict::TooLow).max_by_key(|(value, _)| *value);
+        if let Some((bound, attempt)) = too_low
+            && value <= bound
+        {
+            return Check::OutOfBounds {
+                verdict: Verdict::TooLow,
+                bound: &attempt.answer,
+            };
+        }
+
+        Check::New
+    }
+
+    /// Appends a submission with the current time.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, verdict: Verdict) {
+        self.data.push(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|x| x.as_secs())
+                .unwrap_or_default(),
+        });
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        match json.get("version").and_then(|v| v.get::<f64>()) {
+            Some(v) if *v == f64::from(SCHEMA_VERSION) => {}
+            Some(v) => return Err(format!("unsupported answers schema version {v}.")),
+            None => return Err("expected `json.version` to be a number.".into()),
+        }
+
+        let json_data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Attempt::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Attempt> for JsonValue {
+    fn from(value: &Attempt) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Attempt {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected attempt to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected attempt.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|x| **x == 1.0 || **x == 2.0)
+            .map(|x| *x as u8)
+            .ok_or("Expected attempt.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected attempt.answer to be a string.")?
+            .clone();
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected attempt.verdict to be a string.")?
+            .parse()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected attempt.timestamp to be a number.")?;
+
+        Ok(Attempt {
+            day,
+            part,
+            answer,
+            verdict,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use crate::day;
+
+    use super::{Answers, Check, Verdict};
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "100", Verdict::TooHigh);
+        answers.record(day!(1), 1, "120", Verdict::TooHigh);
+        answers.record(day!(1), 1, "10", Verdict::TooLow);
+        answers.record(day!(1), 1, "50", Verdict::Wrong);
+        answers.record(day!(1), 1, "60", Verdict::RateLimited);
+        answers.record(day!(2), 1, "abc", Verdict::Correct);
+        answers
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(1), 1, "50"),
+            Check::KnownWrong(Verdict::Wrong)
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, "100"),
+            Check::KnownWrong(Verdict::TooHigh)
+        );
+    }
+
+    #[test]
+    fn allows_answers_that_were_rate_limited() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.check(day!(1), 1, "60"), Check::New);
+        assert_eq!(answers.check(day!(1), 2, "50"), Check::New);
+    }
+
+    #[test]
+    fn warns_about_answers_outside_of_bounds() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(1), 1, "110"),
+            Check::OutOfBounds {
+                verdict: Verdict::TooHigh,
+                bound: "100"
+            }
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, "5"),
+            Check::OutOfBounds {
+                verdict: Verdict::TooLow,
+                bound: "10"
+            }
+        );
+        assert_eq!(answers.check(day!(1), 1, "11"), Check::New);
+        assert_eq!(answers.check(day!(1), 1, "not a number"), Check::New);
+    }
+
+    #[test]
+    fn reports_solved_parts() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.correct(day!(2), 1), Some("abc"));
+        assert_eq!(answers.correct(day!(1), 1), None);
+        assert_eq!(answers.check(day!(2), 1, "def"), Check::Solved("abc"));
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(2), 1, Some("abc")), Some(true));
+        assert_eq!(answers.verify(day!(2), 1, Some("def")), Some(false));
+        assert_eq!(answers.verify(day!(2), 1, None), Some(false));
+        assert_eq!(answers.verify(day!(1), 1, Some("50")), None);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = get_mock_answers();
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        let json = r#"{ "version": 1, "data": [{ "day": "01", "part": 3, "answer": "1", "verdict": "correct", "timestamp": 0 }] }"#;
+        assert_eq!(Answers::try_from(json.to_string()).is_err(), true);
+
+        let json = r#"{ "version": 1, "data": [{ "day": "01", "part": 1, "answer": "1", "verdict": "maybe", "timestamp": 0 }] }"#;
+        assert_eq!(Answers::try_from(json.to_string()).is_err(), true);
+
+        let json = r#"{ "version": 2, "data": [] }"#;
+        assert_eq!(Answers::try_from(json.to_string()).is_err(), true);
+    }
+}
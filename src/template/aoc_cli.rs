@@ -4,7 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{Day, create_parent_dir};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -35,6 +35,7 @@ pub fn check() -> Result<(), AocCommandError> {
 
 pub fn read(day: Day) -> Result<Output, AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
+    create_parent_dirs(&[&puzzle_path])?;
 
     let args = build_args(
         "read",
@@ -52,6 +53,7 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
+    create_parent_dirs(&[&input_path, &puzzle_path])?;
 
     let args = build_args(
         "download",
@@ -77,7 +79,11 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // NOTE: the response is captured, so its verdict can be recorded, and printed afterwards.
+    let output = call_aoc_cli_with(&args, Stdio::piped())?;
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    Ok(output)
 }
 
 fn get_input_path(day: Day) -> String {
@@ -88,31 +94,40 @@ fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+/// aoc-cli writes files of other years into their own directories, which need to exist.
+fn create_parent_dirs(paths: &[&str]) -> Result<(), AocCommandError> {
+    for path in paths {
+        create_parent_dir(path).map_err(|_| AocCommandError::CommandNotCallable)?;
     }
+    Ok(())
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    if let Some(year) = day.resolved_year() {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--day".into(),
+        day.into_inner().to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+    call_aoc_cli_with(args, Stdio::inherit())
+}
+
+fn call_aoc_cli_with(args: &[String], stdout: Stdio) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
@@ -0,0 +1,724 @@
+/// Client for the adventofcode.com endpoints that are used to download inputs & puzzles and to submit answers.
+use std::{env, fmt::Display, fs, path::PathBuf, time::Duration};
+
+use ureq::{Agent, Body, http::Response};
+
+use crate::template::{Day, Year, aoc_cli};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// NOTE: the Advent of Code maintainers ask automated tools to identify themselves.
+const USER_AGENT: &str = concat!(
+    "advent-of-code-rust/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie was found in the environment or the session files.
+    MissingSession,
+    /// `AOC_YEAR` is not set to a year.
+    MissingYear,
+    /// The session cookie was rejected, usually because it expired.
+    Unauthorized,
+    /// Too many requests or answers were sent, `wait` is the remaining time if the server reported it.
+    RateLimited { wait: Option<String> },
+    /// The puzzle is not unlocked yet.
+    NotUnlocked,
+    /// The server answered with a status that is not handled otherwise.
+    UnexpectedStatus(u16),
+    /// The server answered a submission with a page that could not be interpreted.
+    UnexpectedResponse(String),
+    /// The request could not be sent or its response could not be read.
+    Request(ureq::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or paste it into \"~/.adventofcode.session\"."
+            ),
+            AocClientError::MissingYear => write!(f, "AOC_YEAR is not set to a valid year."),
+            AocClientError::Unauthorized => write!(
+                f,
+                "the session cookie was rejected, it might have expired. Refresh it from the browser."
+            ),
+            AocClientError::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited by adventofcode.com, try again in {wait}.")
+            }
+            AocClientError::RateLimited { wait: None } => {
+                write!(f, "rate limited by adventofcode.com, try again later.")
+            }
+            AocClientError::NotUnlocked => write!(f, "the puzzle is not unlocked yet."),
+            AocClientError::UnexpectedStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::UnexpectedResponse(text) => {
+                write!(f, "unexpected response from adventofcode.com: {text}")
+            }
+            AocClientError::Request(e) => write!(f, "request to adventofcode.com failed: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Request(e)
+    }
+}
+
+/// Result of submitting an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    /// `hint` tells whether the answer was too high or too low, if the server said so.
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// The part was solved before, so the answer was not checked.
+    AlreadySolved,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Hint::TooHigh => "too high",
+            Hint::TooLow => "too low",
+        })
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "That's the right answer!"),
+            Submission::Incorrect { hint: Some(hint) } => {
+                write!(f, "That's not the right answer, your answer is {hint}.")
+            }
+            Submission::Incorrect { hint: None } => write!(f, "That's not the right answer."),
+            Submission::AlreadySolved => {
+                write!(
+                    f,
+                    "This part was solved already, the answer was not checked."
+                )
+            }
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    /// The year of days without a year of their own.
+    year: u16,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        let agent: Agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Configures the client from the environment.
+    ///
+    /// The session cookie is read from `AOC_SESSION` or `ADVENT_OF_CODE_SESSION`, falling back to the
+    /// `.adventofcode.session` files that aoc-cli reads. `AOC_BASE_URL` overrides the server that is talked to.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let year = env::var("AOC_YEAR")
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .ok_or(AocClientError::MissingYear)?;
+
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url,
+            self.year_of(day),
+            day.into_inner()
+        );
+        self.get(&url)
+    }
+
+    /// Downloads the description of `day`, converted to markdown.
+    ///
+    /// Once the first part is solved, the description includes the second part.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.puzzle_url(day))
+            .map(|html| puzzle_to_markdown(&html))
+    }
+
+    /// The answer that solved `part` of `day`, as shown on its puzzle page.
+    pub fn solved_answer(&self, day: Day, part: u8) -> Result<Option<String>, AocClientError> {
+        let html = self.get(&self.puzzle_url(day))?;
+        Ok(solved_answers(&html).nth(usize::from(part).saturating_sub(1)))
+    }
+
+    /// Submits `answer` for one part of `day`.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Submission, AocClientError> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url,
+            self.year_of(day),
+            day.into_inner()
+        );
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])?;
+
+        parse_submission(&read_body(response)?)
+    }
+
+    fn puzzle_url(&self, day: Day) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            self.year_of(day),
+            day.into_inner()
+        )
+    }
+
+    /// The year of `day`, or the year of the client for days without one.
+    fn year_of(&self, day: Day) -> u16 {
+        day.year().map_or(self.year, Year::into_inner)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        read_body(response)
+    }
+}
+
+/// How the commands talk to adventofcode.com.
+pub enum Backend {
+    Native(AocClient),
+    /// The installed `aoc` binary, used if the native client is not configured.
+    AocCli,
+}
+
+impl Backend {
+    pub fn detect() -> Result<Self, AocClientError> {
+        match AocClient::from_env() {
+            Ok(client) => Ok(Backend::Native(client)),
+            Err(AocClientError::MissingSession | AocClientError::MissingYear)
+                if aoc_cli::check().is_ok() =>
+            {
+                Ok(Backend::AocCli)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn read_body(mut response: Response<Body>) -> Result<String, AocClientError> {
+    match response.status().as_u16() {
+        200 => Ok(response.body_mut().read_to_string()?),
+        // NOTE: the server responds to missing or expired session cookies with 400 or 500.
+        400 | 401 | 403 | 500 => Err(AocClientError::Unauthorized),
+        404 => Err(AocClientError::NotUnlocked),
+        429 => Err(AocClientError::RateLimited { wait: None }),
+        status => Err(AocClientError::UnexpectedStatus(status)),
+    }
+}
+
+fn read_session() -> Option<String> {
+    let from_env = ["AOC_SESSION", "ADVENT_OF_CODE_SESSION"]
+        .into_iter()
+        .find_map(|key| env::var(key).ok());
+
+    let from_file = || {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        let home = PathBuf::from(home);
+
+        let config_dir =
+            env::var_os("XDG_CONFIG_HOME").map_or_else(|| home.join(".config"), PathBuf::from);
+
+        [
+            home.join(".adventofcode.session"),
+            config_dir.join("adventofcode.session"),
+        ]
+        .into_iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+    };
+
+    from_env
+        .or_else(from_file)
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn parse_submission(html: &str) -> Result<Submission, AocClientError> {
+    let text = strip_tags(extract_between(html, "<article", "</article>").unwrap_or(html));
+
+    if text.contains("That's the right answer") {
+        Ok(Submission::Correct)
+    } else if text.contains("That's not the right answer") {
+        let hint = [Hint::TooHigh, Hint::TooLow]
+            .into_iter()
+            .find(|hint| text.contains(&format!("your answer is {hint}")));
+        Ok(Submission::Incorrect { hint })
+    } else if text.contains("You gave an answer too recently") {
+        let wait = extract_between(&text, "You have ", " left to wait").map(str::to_string);
+        Err(AocClientError::RateLimited { wait })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Submission::AlreadySolved)
+    } else {
+        Err(AocClientError::UnexpectedResponse(text.trim().to_string()))
+    }
+}
+
+/// Reads the verdict from the response that aoc-cli prints, which is wrapped to the width of the terminal.
+pub fn parse_cli_submission(output: &str) -> Result<Submission, AocClientError> {
+    parse_submission(&output.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// The answers of the solved parts of a puzzle page, in order.
+fn solved_answers(html: &str) -> impl Iterator<Item = String> {
+    const PREFIX: &str = "Your puzzle answer was <code>";
+
+    html.match_indices(PREFIX).filter_map(|(start, _)| {
+        extract_between(&html[start..], PREFIX, "</code>").map(decode_entities)
+    })
+}
+
+fn extract_between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+    Some(&text[from..to])
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(open) = rest.find('<') {
+        text.push_str(&decode_entities(&rest[..open]));
+        rest = rest[open..]
+            .find('>')
+            .map_or("", |close| &rest[open + close + 1..]);
+    }
+
+    text.push_str(&decode_entities(rest));
+    text
+}
+
+/// Converts the `<article class="day-desc">` elements of a puzzle page to markdown.
+///
+/// Only the handful of elements that puzzle descriptions use are translated, other tags are dropped.
+fn puzzle_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        markdown.push_str(article_to_markdown(&article[..end]).trim_end());
+        markdown.push_str("\n\n");
+        rest = &article[end..];
+    }
+
+    let trimmed = markdown.trim_end();
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("{trimmed}\n")
+    }
+}
+
+fn article_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+    let mut code_depth = 0;
+    // NOTE: markdown has no emphasis inside of code spans, so `<code><em>1</em></code>` becomes *`1`*.
+    let mut emphasized_code = false;
+    let mut rest = html;
+
+    while let Some(open) = rest.find('<') {
+        let text = &rest[..open];
+
+        // NOTE: line breaks between block elements are formatting of the page, not content.
+        if in_pre || !(text.contains('\n') && text.trim().is_empty()) {
+            markdown.push_str(&decode_entities(text));
+        }
+
+        let Some(close) = rest[open..].find('>') else {
+            rest = "";
+            break;
+        };
+
+        let tag = &rest[open + 1..open + close];
+        rest = &rest[open + close + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2" | "p", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            ("code", false) => {
+                code_depth += 1;
+                if !in_pre {
+                    markdown.push('`');
+                }
+            }
+            ("code", true) => {
+                code_depth -= 1;
+                if !in_pre {
+                    markdown.push('`');
+                }
+                if emphasized_code {
+                    emphasized_code = false;
+                    markdown.push('*');
+                }
+            }
+            ("em", false) if code_depth > 0 && !in_pre && markdown.ends_with('`') => {
+                emphasized_code = true;
+                markdown.insert(markdown.len() - 1, '*');
+            }
+            ("em", _) if code_depth == 0 => markdown.push('*'),
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) => markdown.push('\n'),
+            ("ul", true) => markdown.push('\n'),
+            ("br", _) => markdown.push('\n'),
+            ("a", false) => {
+                links.push(
+                    extract_between(tag, "href=\"", "\"")
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({})", decode_entities(&href)));
+            }
+            _ => {}
+        }
+    }
+
+    markdown.push_str(&decode_entities(rest));
+    markdown
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+
+        let replacement = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => name
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| name.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+
+        match (replacement, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves a single request with `status` and `body`, returning the request line and body that were received.
+    fn serve_once(
+        status: u16,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            let mut cookie = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                let lower = line.to_lowercase();
+                if let Some(value) = lower.strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                if lower.starts_with("cookie:") {
+                    cookie = line.trim().to_string();
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            (
+                format!("{} {cookie}", request_line.trim()),
+                String::from_utf8(request_body).unwrap(),
+            )
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, server) = serve_once(200, "1\n2\n3\n");
+        let client = AocClient::new(&base_url, "abc", 2024);
+
+        let input = client.input(Day::new(5).unwrap()).unwrap();
+        let (request, _) = server.join().unwrap();
+
+        assert_eq!(input, "1\n2\n3\n");
+        assert_eq!(
+            request,
+            "GET /2024/day/5/input HTTP/1.1 cookie: session=abc"
+        );
+    }
+
+    #[test]
+    fn downloads_inputs_of_other_years() {
+        let (base_url, server) = serve_once(200, "1\n");
+        let client = AocClient::new(&base_url, "abc", 2024);
+
+        let day = Day::new(5).unwrap().with_year(Year::new(2016).unwrap());
+        client.input(day).unwrap();
+        let (request, _) = server.join().unwrap();
+
+        assert_eq!(
+            request,
+            "GET /2016/day/5/input HTTP/1.1 cookie: session=abc"
+        );
+    }
+
+    #[test]
+    fn maps_status_codes_to_errors() {
+        let cases = [
+            (400, "Unauthorized"),
+            (500, "Unauthorized"),
+            (404, "NotUnlocked"),
+            (429, "RateLimited { wait: None }"),
+            (502, "UnexpectedStatus(502)"),
+        ];
+
+        for (status, expected) in cases {
+            let (base_url, server) = serve_once(status, "");
+            let client = AocClient::new(&base_url, "abc", 2024);
+            let error = client.input(Day::new(1).unwrap()).unwrap_err();
+            server.join().unwrap();
+            assert_eq!(format!("{error:?}"), expected);
+        }
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc", 2024);
+
+        let submission = client.submit(Day::new(3).unwrap(), 2, "42").unwrap();
+        let (request, body) = server.join().unwrap();
+
+        assert_eq!(submission, Submission::Correct);
+        assert_eq!(
+            request,
+            "POST /2024/day/3/answer HTTP/1.1 cookie: session=abc"
+        );
+        assert_eq!(body, "level=2&answer=42");
+    }
+
+    #[test]
+    fn parses_submission_responses() {
+        assert_eq!(
+            parse_submission(
+                "<article><p>That's not the right answer; your answer is too low. If you're stuck...</p></article>"
+            )
+            .unwrap(),
+            Submission::Incorrect {
+                hint: Some(Hint::TooLow)
+            }
+        );
+        assert_eq!(
+            parse_submission(
+                "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"
+            )
+            .unwrap(),
+            Submission::AlreadySolved
+        );
+
+        let rate_limited = parse_submission(
+            "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.</p></article>",
+        );
+        assert_eq!(
+            format!("{:?}", rate_limited.unwrap_err()),
+            "RateLimited { wait: Some(\"4m 2s\") }"
+        );
+    }
+
+    #[test]
+    fn parses_wrapped_cli_responses() {
+        assert_eq!(
+            parse_cli_submission("That's the\nright answer! You are one gold star closer.\n")
+                .unwrap(),
+            Submission::Correct
+        );
+        assert_eq!(
+            parse_cli_submission("That's not the right answer; your answer is too\nhigh.").unwrap(),
+            Submission::Incorrect {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+    }
+
+    #[test]
+    fn reads_solved_answers() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article class=\"day-desc\"></article><p>Your puzzle answer was <code>3</code>.</p><article class=\"day-desc\"></article><p>Your puzzle answer was <code>a&amp;b</code>.</p></main>",
+        );
+        let client = AocClient::new(&base_url, "abc", 2024);
+
+        let answer = client.solved_answer(Day::new(3).unwrap(), 2).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(answer, Some("a&b".into()));
+        assert_eq!(solved_answers("<p>No answers yet.</p>").next(), None);
+    }
+
+    #[test]
+    fn converts_puzzles_to_markdown() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Find the <em>sum</em> of <code>a &amp; b</code>, see <a href="/2024/about">about</a>:</p>
+<p>The sum is <code><em>3</em></code>.</p>
+<pre><code>1 &lt; 2
+<em>3</em>
+</code></pre>
+<ul>
+<li>one</li>
+<li>two</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>3</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again.</p>
+</article>
+</main>"#;
+
+        assert_eq!(
+            puzzle_to_markdown(html),
+            "## --- Day 1: Test ---\n\nFind the *sum* of `a & b`, see [about](/2024/about):\n\nThe sum is *`3`*.\n\n```\n1 < 2\n3\n```\n\n- one\n- two\n\n## --- Part Two ---\n\nAgain.\n"
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; & d"),
+            "a <b> 'c' & d"
+        );
+        assert_eq!(decode_entities("&unknown; &amp"), "&unknown; &amp");
+    }
+}
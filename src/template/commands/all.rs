@@ -1,5 +1,36 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::answers::Answers;
+use crate::template::run_multi::{RunMode, Timeouts, run_multi};
+use crate::template::runner::{RunOptions, Solution};
+use crate::template::{Year, all_days_in};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+/// Runs all days of `year`, or of the year set via `AOC_YEAR` if `None`. Days run in their own process if `isolated` is set or a timeout is configured,
+/// as only those can be stopped. Parts are marked with whether they match their accepted answer.
+pub fn handle(
+    solutions: &[Solution],
+    year: Option<Year>,
+    is_release: bool,
+    isolated: bool,
+    timeouts: Timeouts,
+    perf: bool,
+) {
+    let mode = if isolated || timeouts.is_set() {
+        RunMode::Isolated {
+            is_release,
+            timeouts,
+        }
+    } else {
+        RunMode::InProcess(solutions)
+    };
+
+    let options = RunOptions {
+        perf,
+        ..RunOptions::default()
+    };
+
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Warning: could not read answers, parts are not verified: {e}");
+        Answers::default()
+    });
+
+    run_multi(&all_days_in(year).collect(), mode, options, Some(&answers));
 }
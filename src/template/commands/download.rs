@@ -1,16 +1,49 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::aoc_client::Backend;
+use crate::template::{Day, aoc_cli, create_parent_dir, examples};
+use std::{fs, process};
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+    let client = match Backend::detect() {
+        Ok(Backend::Native(client)) => client,
+        Ok(Backend::AocCli) => {
+            if let Err(e) = aoc_cli::download(day) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+            examples::update(day);
+            return;
+        }
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = format!("data/inputs/{day}.txt");
+    let puzzle_path = format!("data/puzzles/{day}.md");
+
+    let result = client.input(day).and_then(|input| {
+        let puzzle = client.puzzle(day)?;
+        Ok((input, puzzle))
+    });
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let (input, puzzle) = match result {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("failed to download day {day}: {e}");
+            process::exit(1);
+        }
     };
+
+    for (path, contents) in [(&input_path, &input), (&puzzle_path, &puzzle)] {
+        if let Err(e) = create_parent_dir(path).and_then(|()| fs::write(path, contents)) {
+            eprintln!("failed to write \"{path}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    examples::update(day);
 }
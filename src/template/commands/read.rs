@@ -1,17 +1,40 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::aoc_client::Backend;
+use crate::template::{Day, aoc_cli, create_parent_dir, examples};
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    let client = match Backend::detect() {
+        Ok(Backend::Native(client)) => client,
+        Ok(Backend::AocCli) => {
+            if let Err(e) = aoc_cli::read(day) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+            examples::update(day);
+            return;
+        }
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzle = match client.puzzle(day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("failed to read day {day}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzle_path = format!("data/puzzles/{day}.md");
+    if let Err(e) = create_parent_dir(&puzzle_path).and_then(|()| fs::write(&puzzle_path, &puzzle))
+    {
+        eprintln!("failed to write \"{puzzle_path}\": {e}");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    print!("{puzzle}");
+    examples::update(day);
 }
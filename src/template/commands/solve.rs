@@ -1,9 +1,22 @@
-use std::process::{Command, Stdio};
+use std::collections::HashSet;
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::answers::Answers;
+use crate::template::input::InputSource;
+use crate::template::run_multi::child_commands;
+use crate::template::runner::{PartResult, RunOptions};
+use crate::template::watch::{Watcher, diff_answers};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    perf: bool,
+    input: &InputSource,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +36,12 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if perf {
+        cmd_args.push("--perf".to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -32,3 +51,112 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Runs the tests and both parts of a day whenever its solution, the library or its data files change.
+///
+/// After every run, answers that differ from the previous run are listed.
+pub fn watch(day: Day, release: bool, perf: bool) {
+    let mut watcher = Watcher::new(day).unwrap_or_else(|e| {
+        eprintln!("Failed to watch files: {e}");
+        process::exit(1);
+    });
+
+    let options = RunOptions {
+        perf,
+        ..RunOptions::default()
+    };
+
+    let mut previous: Option<Vec<PartResult>> = None;
+
+    loop {
+        let parts = run_once(day, release, options);
+
+        if let (Some(previous), Some(parts)) = (&previous, &parts) {
+            let changes = diff_answers(previous, parts);
+
+            println!();
+            if changes.is_empty() {
+                println!("{ANSI_BOLD}Answers unchanged.{ANSI_RESET}");
+            } else {
+                println!("{ANSI_BOLD}Answers changed:{ANSI_RESET}");
+                changes.iter().for_each(|x| println!("{x}"));
+            }
+        }
+
+        // NOTE: a failed build keeps the answers of the last successful run to compare against.
+        if parts.is_some() {
+            previous = parts;
+        }
+
+        println!();
+        println!("{ANSI_ITALIC}Watching for changes, press Ctrl-C to stop.{ANSI_RESET}");
+
+        match watcher.wait() {
+            Ok(changed) => {
+                println!();
+                for path in changed {
+                    println!("Changed \"{}\".", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to watch files: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Runs the tests of a day, then both parts against its input. Returns `None` if the solution did not build.
+fn run_once(day: Day, release: bool, options: RunOptions) -> Option<Vec<PartResult>> {
+    println!();
+    println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+    println!("-----");
+
+    let mut test_args = vec!["test", "--quiet", "--bin"];
+    let bin = day.bin_name();
+    test_args.push(&bin);
+
+    if release {
+        test_args.push("--release");
+    }
+
+    match Command::new("cargo").args(&test_args).status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => println!("{ANSI_BOLD}Tests failed.{ANSI_RESET}"),
+        Err(e) => eprintln!("Failed to run tests: {e}"),
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    let executables = match child_commands::build_solutions(&HashSet::from([day]), release) {
+        Ok(executables) => executables,
+        Err(e) => {
+            eprintln!("Failed to build the solution: {e:?}");
+            return None;
+        }
+    };
+
+    let Some(executable) = executables.get(&day) else {
+        println!("{ANSI_BOLD}Build failed.{ANSI_RESET}");
+        return None;
+    };
+
+    let answers = Answers::read_from_file().ok();
+
+    match child_commands::run_solution(
+        executable,
+        day,
+        &InputSource::Puzzle,
+        options,
+        None,
+        answers.as_ref(),
+    ) {
+        Ok(result) => Some(result.parts),
+        Err(e) => {
+            eprintln!("Failed to run the solution: {e:?}");
+            None
+        }
+    }
+}
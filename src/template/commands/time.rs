@@ -1,19 +1,46 @@
 use std::collections::HashSet;
+use std::process;
 
-use crate::template::run_multi::run_multi;
+use crate::template::compare::{compare, print_comparisons};
+use crate::template::history::{self, HistoryEntry};
+use crate::template::run_multi::{RunMode, Timeouts, run_multi};
+use crate::template::runner::{RunOptions, Solution};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, Year, all_days_in, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Benches the selected days, which are days of `year` unless a day is passed. Like `cargo all`, days run in their own process if `isolated` is
+/// set, which is implied by `timeouts`.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[Solution],
+    day: Option<Day>,
+    year: Option<Year>,
+    run_all: bool,
+    store: bool,
+    isolated: bool,
+    timeouts: Timeouts,
+    options: RunOptions,
+    compare_threshold: Option<f64>,
+) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days_in(year).collect()
+            } else if compare_threshold.is_some() {
+                // when comparing, default to the days that have a baseline.
+                stored_timings
+                    .data
+                    .iter()
+                    .map(|t| t.day)
+                    .filter(|day| {
+                        day.resolved_year() == year.or_else(Year::configured) && day.is_in_event()
+                    })
+                    .collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days_in(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,7 +48,33 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let mode = if isolated || timeouts.is_set() {
+        RunMode::Isolated {
+            is_release: true,
+            timeouts,
+        }
+    } else {
+        RunMode::InProcess(solutions)
+    };
+
+    let results = run_multi(&days_to_run, mode, options, None);
+    let timings = Timings {
+        data: results
+            .iter()
+            .map(|result| result.timing(options.statistic))
+            .collect(),
+    };
+
+    if !timings.data.is_empty()
+        && let Err(e) = history::append(&HistoryEntry::capture(timings.clone()))
+    {
+        eprintln!("Failed to append run to history: {e}");
+    }
+
+    let has_regressions = compare_threshold.is_some_and(|threshold| {
+        let comparison = compare(&stored_timings, &results, options.statistic, threshold);
+        print_comparisons(&comparison, threshold)
+    });
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
@@ -37,4 +90,13 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Prints how the stored timings of `day` evolved across runs.
+pub fn handle_history(day: Day) {
+    history::print_day(&history::read_from_file(), day);
 }
@@ -0,0 +1,272 @@
+use std::collections::HashSet;
+use std::{fs, process};
+
+use crate::template::answers::Answers;
+use crate::template::input::{InputSource, named_inputs, named_inputs_dir};
+use crate::template::run_multi::{
+    DayResult, RunMode, Timeouts, child_commands, get_path_for_input, run_multi,
+};
+use crate::template::runner::{PartResult, RunOptions, Solution, find_solution};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days_in};
+
+/// Runs days against their real input and compares the answers with the accepted answers in `data/answers.json`.
+///
+/// Without a day, all days of `year` with an accepted answer are verified. Exits with a non-zero status on any mismatch.
+///
+/// With `all_inputs`, the puzzle input and the named inputs in `data/inputs/<day>/` are verified instead, see [`verify_all_inputs`].
+pub fn handle(
+    solutions: &[Solution],
+    day: Option<Day>,
+    year: Option<Year>,
+    is_release: bool,
+    isolated: bool,
+    all_inputs: bool,
+) {
+    let answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("failed to read answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    if all_inputs {
+        verify_all_inputs(solutions, day, year, is_release, isolated, &answers);
+        return;
+    }
+
+    let days_to_run: HashSet<Day> = match day {
+        Some(day) => HashSet::from([day]),
+        None => all_days_in(year)
+            .filter(|day| (1..=2).any(|part| answers.correct(*day, part).is_some()))
+            .collect(),
+    };
+
+    if days_to_run.is_empty() {
+        println!(
+            "No accepted answers to verify against yet, they are recorded when submitting with `--submit`."
+        );
+        return;
+    }
+
+    let mode = if isolated {
+        RunMode::Isolated {
+            is_release,
+            timeouts: Timeouts::default(),
+        }
+    } else {
+        RunMode::InProcess(solutions)
+    };
+
+    let results = run_multi(&days_to_run, mode, RunOptions::default(), Some(&answers));
+
+    let mut days: Vec<Day> = days_to_run.into_iter().collect();
+    days.sort();
+
+    println!();
+    println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------------");
+    println!("Day  Part 1  Part 2");
+
+    let mut failed = 0;
+
+    for day in days {
+        let result = results.iter().find(|result| result.day == day);
+
+        let markers = [1, 2].map(
+            |part| match answers.verify(day, part, answer_of(result, part)) {
+                Some(true) => "✔",
+                Some(false) => {
+                    failed += 1;
+                    "✘"
+                }
+                None => "-",
+            },
+        );
+
+        println!("{day}   {:<6}  {}", markers[0], markers[1]);
+    }
+
+    if failed > 0 {
+        eprintln!("\n{failed} part(s) did not produce the accepted answer.");
+        process::exit(1);
+    }
+}
+
+fn answer_of(result: Option<&DayResult>, part: u8) -> Option<&str> {
+    result?
+        .parts
+        .iter()
+        .find(|x| x.part == part)
+        .and_then(|x| x.answer.as_deref())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An input to verify a day against, with the answers it is known to have.
+struct Check {
+    name: String,
+    source: InputSource,
+    expected: [Option<String>; 2],
+}
+
+/// Runs days against every input they have and lists the inputs that do not produce their known answers.
+///
+/// Besides the puzzle input, which is compared with `data/answers.json`, every `data/inputs/<day>/<name>.txt`
+/// is run and compared with the answers in `<name>.answers`. Without a day, all days of `year` with named inputs are verified.
+fn verify_all_inputs(
+    solutions: &[Solution],
+    day: Option<Day>,
+    year: Option<Year>,
+    is_release: bool,
+    isolated: bool,
+    answers: &Answers,
+) {
+    let days: Vec<Day> = match day {
+        Some(day) => vec![day],
+        None => all_days_in(year)
+            .filter(|day| named_inputs_dir(*day).is_dir())
+            .collect(),
+    };
+
+    if days.is_empty() {
+        println!("No named inputs to verify yet, add them to `data/inputs/<day>/<name>.txt`.");
+        return;
+    }
+
+    let executables = if isolated {
+        child_commands::build_solutions(&days.iter().copied().collect(), is_release).unwrap()
+    } else {
+        Default::default()
+    };
+
+    let options = RunOptions {
+        quiet: true,
+        ..RunOptions::default()
+    };
+
+    let mut failures: Vec<String> = vec![];
+    let mut total = 0;
+
+    for (i, day) in days.into_iter().enumerate() {
+        let checks = match checks_of(day, answers) {
+            Ok(checks) => checks,
+            Err(e) => {
+                eprintln!("Failed to read inputs of day {day}: {e}");
+                process::exit(1);
+            }
+        };
+
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let name_width = checks
+            .iter()
+            .map(|x| x.name.len())
+            .max()
+            .unwrap_or(0)
+            .max(5);
+        println!("{:<name_width$}  Part 1  Part 2", "Input");
+
+        for check in checks {
+            total += 1;
+
+            let parts = if isolated {
+                executables.get(&day).and_then(|executable| {
+                    child_commands::run_solution(
+                        executable,
+                        day,
+                        &check.source,
+                        options,
+                        None,
+                        None,
+                    )
+                    .ok()
+                    .map(|result| result.parts)
+                })
+            } else {
+                run_in_process(solutions, day, &check.source, options)
+            };
+
+            let mut mismatches = vec![];
+
+            let markers = [1, 2].map(|part| {
+                let expected = check.expected[usize::from(part - 1)].as_deref();
+                let answer = parts.as_deref().and_then(|parts| answer_in(parts, part));
+
+                match expected {
+                    Some(expected) if Some(expected) == answer => "✔",
+                    Some(expected) => {
+                        mismatches.push(format!(
+                            "part {part} expected {expected}, got {}",
+                            answer.unwrap_or("nothing")
+                        ));
+                        "✘"
+                    }
+                    None => "-",
+                }
+            });
+
+            println!(
+                "{:<name_width$}  {:<6}  {}",
+                check.name, markers[0], markers[1]
+            );
+
+            if parts.is_none() {
+                failures.push(format!("{day} {}: did not run.", check.name));
+            } else if !mismatches.is_empty() {
+                failures.push(format!("{day} {}: {}.", check.name, mismatches.join(", ")));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        eprintln!("\n{} of {total} input(s) failed:", failures.len());
+        failures.iter().for_each(|x| eprintln!("  {x}"));
+        process::exit(1);
+    }
+}
+
+/// The inputs of a day: its puzzle input, if there is one, followed by its named inputs.
+fn checks_of(day: Day, answers: &Answers) -> Result<Vec<Check>, String> {
+    let mut checks = vec![];
+
+    if fs::exists(get_path_for_input(day)).unwrap_or(false) {
+        checks.push(Check {
+            name: "puzzle".into(),
+            source: InputSource::Puzzle,
+            expected: [1, 2].map(|part| answers.correct(day, part).map(ToString::to_string)),
+        });
+    }
+
+    checks.extend(named_inputs(day)?.into_iter().map(|input| Check {
+        name: input.name,
+        source: InputSource::Path(input.path),
+        expected: input.answers,
+    }));
+
+    Ok(checks)
+}
+
+/// Runs a day in-process against `source`, returning `None` if there is no solution or the input can not be read.
+fn run_in_process(
+    solutions: &[Solution],
+    day: Day,
+    source: &InputSource,
+    options: RunOptions,
+) -> Option<Vec<PartResult>> {
+    let solution = find_solution(solutions, day)?;
+    let input = source.try_read(day).ok()?;
+    Some((solution.run)(&input, options, None))
+}
+
+fn answer_in(parts: &[PartResult], part: u8) -> Option<&str> {
+    parts
+        .iter()
+        .find(|x| x.part == part)
+        .and_then(|x| x.answer.as_deref())
+}
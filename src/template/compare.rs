@@ -0,0 +1,331 @@
+/// Compares the results of a timed run against stored timings to detect performance regressions.
+use std::time::Duration;
+
+use crate::template::run_multi::DayResult;
+use crate::template::stats::Statistic;
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+
+/// Two-sided z-score for a confidence of 99%.
+const Z_CRITICAL: f64 = 2.576;
+
+/// How the timing of a part changed compared to its stored baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    /// Either not statistically significant or within the threshold.
+    Unchanged,
+}
+
+/// Comparison of a single part against its stored baseline.
+#[derive(Clone, Debug)]
+pub struct PartComparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+    /// Relative change in percent, positive values are slower.
+    pub delta_percent: f64,
+    pub verdict: Verdict,
+}
+
+/// Comparison of a run against the stored baselines.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    /// The statistic that the timings of the run represent.
+    pub statistic: Statistic,
+    pub parts: Vec<PartComparison>,
+    /// Days that were skipped because their baseline represents another statistic, with that statistic.
+    pub skipped: Vec<(Day, Statistic)>,
+}
+
+/// Compares every solved and timed part in `results` that has a stored baseline. The results
+/// represent `statistic` of their samples, days with a baseline of another statistic are skipped.
+///
+/// A part counts as changed if its timing moved by more than `threshold_percent` and the move is
+/// statistically significant. Significance is approximated with a z-test of the stored value
+/// against the standard error of the current samples, as only one value is stored per part.
+pub fn compare(
+    baseline: &Timings,
+    results: &[DayResult],
+    statistic: Statistic,
+    threshold_percent: f64,
+) -> Comparison {
+    let mut comparison = Comparison {
+        statistic,
+        parts: vec![],
+        skipped: vec![],
+    };
+
+    for day_result in results {
+        let Some(stored) = baseline.data.iter().find(|t| t.day == day_result.day) else {
+            continue;
+        };
+
+        // e.g. the median is usually below the mean, comparing them would report false changes.
+        if stored.statistic != statistic {
+            comparison.skipped.push((day_result.day, stored.statistic));
+            continue;
+        }
+
+        for part in day_result.parts.iter().filter(|part| part.answer.is_some()) {
+            let (Some(baseline_nanos), Some(stats)) = (stored.part_nanos(part.part), part.stats)
+            else {
+                continue;
+            };
+
+            // NOTE: a baseline below the resolution of the clock has no relative change to compare.
+            if baseline_nanos == 0_f64 {
+                continue;
+            }
+
+            let current_nanos = as_nanos(part.duration);
+            let delta_percent = (current_nanos - baseline_nanos) / baseline_nanos * 100_f64;
+
+            #[allow(clippy::cast_precision_loss)]
+            let std_error = as_nanos(stats.stddev) / (part.samples as f64).sqrt();
+
+            let is_significant = if std_error == 0_f64 {
+                current_nanos != baseline_nanos
+            } else {
+                ((current_nanos - baseline_nanos) / std_error).abs() > Z_CRITICAL
+            };
+
+            let verdict = match delta_percent {
+                _ if !is_significant => Verdict::Unchanged,
+                d if d > threshold_percent => Verdict::Regression,
+                d if d < -threshold_percent => Verdict::Improvement,
+                _ => Verdict::Unchanged,
+            };
+
+            comparison.parts.push(PartComparison {
+                day: day_result.day,
+                part: part.part,
+                baseline_nanos,
+                current_nanos,
+                delta_percent,
+                verdict,
+            });
+        }
+    }
+
+    comparison
+}
+
+/// Prints a comparison table, returns `true` if any part regressed.
+pub fn print_comparisons(comparison: &Comparison, threshold_percent: f64) -> bool {
+    println!(
+        "\n{ANSI_BOLD}Comparison{ANSI_RESET} {ANSI_ITALIC}(threshold: {threshold_percent}%){ANSI_RESET}"
+    );
+    println!("------");
+
+    for (day, statistic) in &comparison.skipped {
+        println!(
+            "Day {day}: skipped, the stored timings are the {statistic} of their samples, not the {}. Rerun with `--stat {statistic}` to compare.",
+            comparison.statistic
+        );
+    }
+
+    if comparison.parts.is_empty() {
+        if comparison.skipped.is_empty() {
+            println!("No stored timings to compare against.");
+        }
+        return false;
+    }
+
+    for part in &comparison.parts {
+        let line = format!(
+            "Day {} Part {}: {:.1?} → {:.1?} ({:+.1}%)",
+            part.day,
+            part.part,
+            to_duration(part.baseline_nanos),
+            to_duration(part.current_nanos),
+            part.delta_percent
+        );
+
+        match part.verdict {
+            Verdict::Regression => println!("{ANSI_BOLD}{line} regression{ANSI_RESET}"),
+            Verdict::Improvement => println!("{line} improvement"),
+            Verdict::Unchanged => println!("{line}"),
+        }
+    }
+
+    let regressions = comparison
+        .parts
+        .iter()
+        .filter(|c| c.verdict == Verdict::Regression)
+        .count();
+
+    if regressions > 0 {
+        println!("\n{ANSI_BOLD}{regressions} regression(s) detected.{ANSI_RESET}");
+    }
+
+    regressions > 0
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn as_nanos(duration: Duration) -> f64 {
+    duration.as_nanos() as f64
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Verdict, compare};
+    use crate::{
+        day,
+        template::{
+            run_multi::DayResult,
+            runner::PartResult,
+            stats::{BenchStats, Statistic},
+            timings::{PartTiming, Timing, Timings},
+        },
+    };
+
+    fn get_baseline() -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                statistic: Statistic::Mean,
+                parse: None,
+                part_1: Some(PartTiming {
+                    nanos: 100_000,
+                    samples: Some(3),
+                    allocs: None,
+                    perf: None,
+                }),
+                part_2: Some(PartTiming {
+                    nanos: 1_000_000,
+                    samples: Some(3),
+                    allocs: None,
+                    perf: None,
+                }),
+                total_nanos: 1_100_000_f64,
+                timed_out: false,
+            }],
+        }
+    }
+
+    fn get_result(part: u8, nanos: &[u64]) -> PartResult {
+        let samples: Vec<Duration> = nanos.iter().map(|&x| Duration::from_nanos(x)).collect();
+        let stats = BenchStats::from_samples(&samples).unwrap();
+
+        PartResult {
+            part,
+            answer: Some("42".into()),
+            error: None,
+            duration: stats.mean,
+            samples: samples.len() as u128,
+            stats: Some(stats),
+            allocs: None,
+            perf: None,
+        }
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let results = [DayResult {
+            day: day!(1),
+            parts: vec![
+                get_result(1, &[299_000, 300_000, 301_000]),
+                get_result(2, &[999_000, 1_000_000, 1_001_000]),
+            ],
+            timed_out: false,
+        }];
+
+        let comparisons = compare(&get_baseline(), &results, Statistic::Mean, 10_f64).parts;
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].verdict, Verdict::Regression);
+        assert_eq!(comparisons[0].delta_percent, 200_f64);
+        assert_eq!(comparisons[1].verdict, Verdict::Unchanged);
+    }
+
+    #[test]
+    fn detects_improvements() {
+        let results = [DayResult {
+            day: day!(1),
+            parts: vec![get_result(1, &[49_000, 50_000, 51_000])],
+            timed_out: false,
+        }];
+
+        let comparisons = compare(&get_baseline(), &results, Statistic::Mean, 10_f64).parts;
+        assert_eq!(comparisons[0].verdict, Verdict::Improvement);
+    }
+
+    #[test]
+    fn ignores_changes_within_threshold() {
+        let results = [DayResult {
+            day: day!(1),
+            parts: vec![get_result(1, &[104_000, 105_000, 106_000])],
+            timed_out: false,
+        }];
+
+        let comparisons = compare(&get_baseline(), &results, Statistic::Mean, 10_f64).parts;
+        assert_eq!(comparisons[0].verdict, Verdict::Unchanged);
+    }
+
+    #[test]
+    fn ignores_insignificant_changes() {
+        let results = [DayResult {
+            day: day!(1),
+            parts: vec![get_result(1, &[10_000, 300_000, 10_000, 300_000])],
+            timed_out: false,
+        }];
+
+        let comparisons = compare(&get_baseline(), &results, Statistic::Mean, 10_f64).parts;
+        assert_eq!(comparisons[0].verdict, Verdict::Unchanged);
+    }
+
+    #[test]
+    fn skips_days_without_baseline() {
+        let results = [DayResult {
+            day: day!(2),
+            parts: vec![get_result(1, &[1, 2, 3])],
+            timed_out: false,
+        }];
+
+        assert_eq!(
+            compare(&get_baseline(), &results, Statistic::Mean, 10_f64)
+                .parts
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn skips_baselines_of_other_statistics() {
+        let results = [DayResult {
+            day: day!(1),
+            parts: vec![get_result(1, &[299_000, 300_000, 301_000])],
+            timed_out: false,
+        }];
+
+        let comparison = compare(&get_baseline(), &results, Statistic::Median, 10_f64);
+        assert_eq!(comparison.parts.len(), 0);
+        assert_eq!(comparison.skipped, vec![(day!(1), Statistic::Mean)]);
+    }
+
+    #[test]
+    fn skips_zero_baselines() {
+        let mut baseline = get_baseline();
+        baseline.data[0].part_1.as_mut().unwrap().nanos = 0;
+
+        let results = [DayResult {
+            day: day!(1),
+            parts: vec![get_result(1, &[299_000, 300_000, 301_000])],
+            timed_out: false,
+        }];
+
+        let comparisons = compare(&baseline, &results, Statistic::Mean, 10_f64).parts;
+        assert_eq!(comparisons.len(), 0);
+    }
+}
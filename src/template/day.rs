@@ -2,24 +2,34 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent (i.e. an integer in range 1 to 25), optionally of a specific year.
+/// Events from 2025 on only have 12 days, see [`Day::is_in_event`].
+///
+/// Days without a year belong to the year set via `AOC_YEAR`, their files live in `src/bin/NN.rs`
+/// and `data/*/NN.*`. Days of other years live in `src/bin/YYYY-NN.rs` and `data/*/YYYY/NN.*`.
 ///
 /// # Display
-/// This value displays as a two digit number.
+/// This value displays as a two digit number, prefixed with the year if it has one.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::{Day, Year};
 /// let day = Day::new(8).unwrap();
-/// assert_eq!(day.to_string(), "08")
+/// assert_eq!(day.to_string(), "08");
+/// assert_eq!(day.with_year(Year::new(2024).unwrap()).to_string(), "2024/08");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Day(u8);
+pub struct Day {
+    year: Option<Year>,
+    day: u8,
+}
 
 impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
@@ -28,22 +38,122 @@ impl Day {
         if day == 0 || day > 25 {
             return None;
         }
-        Some(Self(day))
+        Some(Self { year: None, day })
     }
 
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
-        self.0
+        self.day
+    }
+
+    /// The year of the day, if it is not the year set via `AOC_YEAR`.
+    pub fn year(self) -> Option<Year> {
+        self.year
+    }
+
+    /// The year of the day, falling back to the year set via `AOC_YEAR`.
+    pub fn resolved_year(self) -> Option<Year> {
+        self.year.or_else(Year::configured)
+    }
+
+    /// Whether the day is part of the event of its year, e.g. the 13th is not part of the 2025 event.
+    /// Days of an unknown year are assumed to be part of a 25-day event.
+    pub fn is_in_event(self) -> bool {
+        self.day <= self.resolved_year().map_or(25, Year::days)
+    }
+
+    /// Moves the day to `year`.
+    #[must_use]
+    pub const fn with_year(self, year: Year) -> Self {
+        Self {
+            year: Some(year),
+            ..self
+        }
+    }
+
+    /// Moves the day to the year of its solution file, if the file is named like `2024-05.rs`. Files of the
+    /// year set via `AOC_YEAR` leave the day without a year, like [`Day::from_str`] does.
+    ///
+    /// Used by `solution!`, so solutions know their year from the file they are in.
+    #[must_use]
+    pub const fn with_year_of_file(self, path: &str) -> Self {
+        let bytes = path.as_bytes();
+
+        // the file name starts after the last path separator.
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+
+        if bytes.len() - start < 5 || bytes[start + 4] != b'-' {
+            return self;
+        }
+
+        let Some(year) = parse_year(bytes, start) else {
+            return self;
+        };
+
+        // the configured year is the one of days without a year, like in `Day::from_str`.
+        if let Some(configured) = CONFIGURED_YEAR
+            && configured.len() == 4
+            && let Some(configured) = parse_year(configured.as_bytes(), 0)
+            && configured == year
+        {
+            return self;
+        }
+
+        match Year::new(year) {
+            Some(year) => self.with_year(year),
+            None => self,
+        }
+    }
+
+    /// Name of the solution binary of the day, e.g. `05` or `2024-05`.
+    pub fn bin_name(self) -> String {
+        match self.year {
+            Some(year) => format!("{year}-{:02}", self.day),
+            None => format!("{:02}", self.day),
+        }
     }
 }
 
+/// The year set via `AOC_YEAR` when the library was compiled. [`Day::with_year_of_file`] runs in const contexts,
+/// where [`Year::configured`] can not be read. Cargo rebuilds the library when the variable changes.
+const CONFIGURED_YEAR: Option<&str> = option_env!("AOC_YEAR");
+
+/// Parses the four digits of a year at `start` of `bytes`.
+const fn parse_year(bytes: &[u8], start: usize) -> Option<u16> {
+    if bytes.len() < start + 4 {
+        return None;
+    }
+
+    let mut year = 0;
+    let mut i = 0;
+    while i < 4 {
+        let digit = bytes[start + i];
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (digit - b'0') as u16;
+        i += 1;
+    }
+
+    Some(year)
+}
+
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this year's event in december (the 1st to the 25th, or to the 12th
+    /// from 2025 on), `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        let days = u16::try_from(today.year())
+            .ok()
+            .and_then(Year::new)
+            .map_or(25, Year::days);
+
+        if today.month() == 12 && today.day() <= u32::from(days) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -53,19 +163,22 @@ impl Day {
 
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:02}", self.0)
+        match self.year {
+            Some(year) => write!(f, "{year}/{:02}", self.day),
+            None => write!(f, "{:02}", self.day),
+        }
     }
 }
 
 impl PartialEq<u8> for Day {
     fn eq(&self, other: &u8) -> bool {
-        self.0.eq(other)
+        self.day.eq(other)
     }
 }
 
 impl PartialOrd<u8> for Day {
     fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
+        self.day.partial_cmp(other)
     }
 }
 
@@ -74,9 +187,34 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses days like `05`, `2024/05` or the binary name `2024-05`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let day = Self::from_str_outside_event(s)?;
+
+        if !day.is_in_event() {
+            return Err(DayFromStrError);
+        }
+
+        Ok(day)
+    }
+}
+
+impl Day {
+    /// Parses a day like [`Day::from_str`], but also accepts days outside the event of their year (e.g. day 13 of
+    /// 2025), so that files written by earlier versions can still be read.
+    pub(crate) fn from_str_outside_event(s: &str) -> Result<Self, DayFromStrError> {
+        let (year, day) = match s.split_once(['/', '-']) {
+            Some((year, day)) => (Some(year.parse().map_err(|_| DayFromStrError)?), day),
+            None => (None, s),
+        };
+
+        let day = day.parse().map_err(|_| DayFromStrError)?;
+        let day = Self::new(day).ok_or(DayFromStrError)?;
+        // the configured year is the one of days without a year, so `2025/05` and `05` name the same day.
+        Ok(match year {
+            Some(year) if Some(year) != Year::configured() => day.with_year(year),
+            _ => day,
+        })
     }
 }
 
@@ -88,26 +226,38 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        f.write_str("expecting a day number between 1 and 25 (12 from 2025 on), optionally prefixed with a year like `2024/05`")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of the year set via `AOC_YEAR`.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of `year`, or of the year set via `AOC_YEAR` if `None`.
+pub fn all_days_in(year: Option<Year>) -> AllDays {
+    let year = year.filter(|year| Some(*year) != Year::configured());
+    AllDays {
+        year,
+        current: 1,
+        last: year.or_else(Year::configured).map_or(25, Year::days),
+    }
+}
+
+/// An iterator that yields every day of advent of a year, from the 1st to the 25th (or the 12th from 2025 on).
 pub struct AllDays {
+    year: Option<Year>,
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        all_days_in(None)
     }
 }
 
@@ -115,11 +265,14 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
-        let day = Day(self.current);
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day of the year.
+        let day = Day {
+            year: self.year,
+            day: self.current,
+        };
         self.current += 1;
 
         Some(day)
@@ -143,39 +296,111 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use std::str::FromStr;
+
+    use super::{Day, all_days_in};
+    use crate::template::Year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
-
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        let year = Year::new(2024).unwrap();
+        let day = |n| Day::new(n).map(|day| day.with_year(year));
+        let mut iter = all_days_in(Some(year));
+
+        assert_eq!(iter.next(), day(1));
+        assert_eq!(iter.next(), day(2));
+        assert_eq!(iter.next(), day(3));
+        assert_eq!(iter.next(), day(4));
+        assert_eq!(iter.next(), day(5));
+        assert_eq!(iter.next(), day(6));
+        assert_eq!(iter.next(), day(7));
+        assert_eq!(iter.next(), day(8));
+        assert_eq!(iter.next(), day(9));
+        assert_eq!(iter.next(), day(10));
+        assert_eq!(iter.next(), day(11));
+        assert_eq!(iter.next(), day(12));
+        assert_eq!(iter.next(), day(13));
+        assert_eq!(iter.next(), day(14));
+        assert_eq!(iter.next(), day(15));
+        assert_eq!(iter.next(), day(16));
+        assert_eq!(iter.next(), day(17));
+        assert_eq!(iter.next(), day(18));
+        assert_eq!(iter.next(), day(19));
+        assert_eq!(iter.next(), day(20));
+        assert_eq!(iter.next(), day(21));
+        assert_eq!(iter.next(), day(22));
+        assert_eq!(iter.next(), day(23));
+        assert_eq!(iter.next(), day(24));
+        assert_eq!(iter.next(), day(25));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_shorter_events() {
+        let year = Year::new(2026).unwrap();
+        let days: Vec<Day> = all_days_in(Some(year)).collect();
+
+        assert_eq!(days.len(), 12);
+        assert_eq!(
+            days.last(),
+            Day::new(12).map(|d| d.with_year(year)).as_ref()
+        );
+    }
+
+    #[test]
+    fn parses_days_of_years() {
+        let year = Year::new(2024).unwrap();
+        let day = Day::new(5).unwrap();
+
+        assert_eq!(Day::from_str("05").ok(), Some(day));
+        assert_eq!(Day::from_str("2024/05").ok(), Some(day.with_year(year)));
+        assert_eq!(Day::from_str("2024-5").ok(), Some(day.with_year(year)));
+        assert_eq!(Day::from_str("2014/05").is_err(), true);
+        assert_eq!(Day::from_str("2024/26").is_err(), true);
+        assert_eq!(Day::from_str("2024/13").is_ok(), true);
+        assert_eq!(Day::from_str("2025/13").is_err(), true);
+
+        assert_eq!(day.with_year(year).to_string(), "2024/05");
+        assert_eq!(day.with_year(year).bin_name(), "2024-05");
+        assert_eq!(day.bin_name(), "05");
+    }
+
+    #[test]
+    fn treats_the_configured_year_like_no_year() {
+        // NOTE: cargo sets `AOC_YEAR` via `.cargo/config.toml`.
+        let year = Year::configured().unwrap();
+        let day = Day::from_str(&format!("{year}/05")).unwrap();
+
+        assert_eq!(day, Day::from_str("05").unwrap());
+        assert_eq!(day.bin_name(), "05");
+        assert_eq!(
+            Day::new(5)
+                .unwrap()
+                .with_year_of_file(&format!("src/bin/{year}-05.rs")),
+            day
+        );
+        assert_eq!(
+            all_days_in(Some(year)).collect::<Vec<_>>(),
+            all_days_in(None).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn reads_years_of_files() {
+        let day = Day::new(5).unwrap();
+        let year = Year::new(2024).unwrap();
+
+        assert_eq!(day.with_year_of_file("src/bin/05.rs"), day);
+        assert_eq!(
+            day.with_year_of_file("/repo/src/bin/2024-05.rs"),
+            day.with_year(year)
+        );
+        assert_eq!(
+            day.with_year_of_file("src\\bin\\2024-05.rs"),
+            day.with_year(year)
+        );
+        assert_eq!(day.with_year_of_file("src/bin/abcd-05.rs"), day);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,286 @@
+/// Extracts examples and their expected answers from downloaded puzzle descriptions.
+use std::{fs, path::Path};
+
+use crate::template::Day;
+
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+// NOTE: tests are only filled in while they still read like the scaffolded ones of `src/templates`.
+const TEMPLATE_ASSERTION: &str = "assert_eq!(result, None);";
+const TEMPLATE_PART_TWO_INPUT: &str =
+    "part_two(&advent_of_code::template::read_file(\"examples\", DAY));";
+const PART_TWO_EXAMPLE_INPUT: &str = "part_two(&advent_of_code::template::read_file_part(
+            \"examples\", DAY, 2,
+        ));";
+
+/// Example of a single part of a puzzle.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    /// The example input, if the part introduces one.
+    pub input: Option<String>,
+    /// The last highlighted code span of the part, which usually is the answer for the example.
+    pub answer: Option<String>,
+}
+
+/// Examples of both parts. The second part is only present once it is unlocked.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part_one: Example,
+    pub part_two: Option<Example>,
+}
+
+/// Finds the examples in the markdown of a puzzle description, as written by the `download` and `read` commands.
+///
+/// The example input of a part is its first code block that follows a paragraph mentioning an example.
+/// For the first part, its first code block is used if no paragraph mentions one. The second part often
+/// reuses the example of the first, so it only gets an input of its own if it introduces a different one.
+pub fn extract(markdown: &str) -> Examples {
+    let (part_one, part_two) = match markdown.find(PART_TWO_HEADING) {
+        Some(index) => (&markdown[..index], Some(&markdown[index..])),
+        None => (markdown, None),
+    };
+
+    let part_one = extract_part(part_one, true);
+
+    let part_two = part_two.map(|markdown| {
+        let mut example = extract_part(markdown, false);
+        if example.input == part_one.input {
+            example.input = None;
+        }
+        example
+    });
+
+    Examples { part_one, part_two }
+}
+
+fn extract_part(markdown: &str, fallback_to_first_block: bool) -> Example {
+    // code blocks, along with the paragraph that precedes them.
+    let mut blocks: Vec<(String, String)> = vec![];
+    let mut answer = None;
+
+    let mut paragraph = String::new();
+    let mut preceding = String::new();
+    let mut code: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match code.take() {
+                Some(code) => blocks.push((std::mem::take(&mut preceding), code)),
+                None => {
+                    if !paragraph.is_empty() {
+                        preceding = std::mem::take(&mut paragraph);
+                    }
+                    code = Some(String::new());
+                }
+            }
+            continue;
+        }
+
+        if let Some(code) = code.as_mut() {
+            code.push_str(line);
+            code.push('\n');
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                preceding = std::mem::take(&mut paragraph);
+            }
+        } else {
+            paragraph.push_str(line);
+            paragraph.push(' ');
+            answer = last_highlight(line).or(answer);
+        }
+    }
+
+    let input = blocks
+        .iter()
+        .find(|(preceding, _)| preceding.to_lowercase().contains("example"))
+        .or(blocks.first().filter(|_| fallback_to_first_block))
+        .map(|(_, code)| code.clone());
+
+    Example { input, answer }
+}
+
+/// The content of the last highlighted code span of a line, written as *`...`*.
+fn last_highlight(line: &str) -> Option<String> {
+    let start = line.rfind("*`")? + 2;
+    let end = start + line[start..].find("`*")?;
+    Some(line[start..end].to_string())
+}
+
+/// Writes extracted examples to `data/examples` and fills their answers into the scaffolded tests of `day`.
+///
+/// Example files that have contents already and tests that were edited are left untouched.
+pub fn apply(day: Day, examples: &Examples) {
+    if let Some(input) = &examples.part_one.input {
+        write_example(&format!("data/examples/{day}.txt"), input);
+    }
+
+    let part_two_input = examples.part_two.as_ref().and_then(|x| x.input.as_ref());
+
+    if let Some(input) = part_two_input {
+        write_example(&format!("data/examples/{day}-2.txt"), input);
+    }
+
+    let module_path = format!("src/bin/{}.rs", day.bin_name());
+
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    let Some(index) = module.find("fn test_part_two") else {
+        return;
+    };
+
+    let (test_one, test_two) = module.split_at(index);
+
+    let test_one = fill_answer(test_one, examples.part_one.answer.as_deref());
+
+    let test_two = match &examples.part_two {
+        Some(example) => {
+            let test_two = fill_answer(test_two, example.answer.as_deref());
+            if part_two_input.is_some() {
+                test_two.replacen(TEMPLATE_PART_TWO_INPUT, PART_TWO_EXAMPLE_INPUT, 1)
+            } else {
+                test_two
+            }
+        }
+        None => test_two.to_string(),
+    };
+
+    let updated = format!("{test_one}{test_two}");
+
+    if updated != module {
+        match fs::write(&module_path, updated) {
+            Ok(()) => println!("Filled example answers into the tests of \"{module_path}\"."),
+            Err(e) => eprintln!("Failed to fill example answers into \"{module_path}\": {e}"),
+        }
+    }
+}
+
+/// Replaces the assertion of a scaffolded test, if the answer is a number like the template's return types.
+fn fill_answer(test: &str, answer: Option<&str>) -> String {
+    match answer.and_then(|x| x.parse::<u64>().ok()) {
+        Some(answer) => test.replacen(
+            TEMPLATE_ASSERTION,
+            &format!("assert_eq!(result, Some({answer}));"),
+            1,
+        ),
+        None => test.to_string(),
+    }
+}
+
+fn write_example(path: &str, input: &str) {
+    let is_empty = fs::read_to_string(path).map_or(true, |x| x.trim().is_empty());
+
+    if !is_empty || !Path::new(path).parent().is_some_and(Path::exists) {
+        return;
+    }
+
+    match fs::write(path, input) {
+        Ok(()) => println!("Wrote example to \"{path}\"."),
+        Err(e) => eprintln!("Failed to write example to \"{path}\": {e}"),
+    }
+}
+
+/// Extracts the examples of the downloaded puzzle of `day`, if there is one, see [`extract`] and [`apply`].
+pub fn update(day: Day) {
+    if let Ok(markdown) = fs::read_to_string(format!("data/puzzles/{day}.md")) {
+        apply(day, &extract(&markdown));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Examples, extract, fill_answer, last_highlight};
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+Some introduction with `code`.
+
+```
+not the example
+```
+
+For example, suppose
+the lists are:
+
+```
+3   4
+4   3
+```
+
+The first pair is *`1`*.
+
+In this example, the total is *`11`*.
+
+## --- Part Two ---
+
+Now, count the same list:
+
+```
+3   4
+4   3
+```
+
+So, the score is *`31`*.
+";
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(
+            extract(PUZZLE),
+            Examples {
+                part_one: Example {
+                    input: Some("3   4\n4   3\n".into()),
+                    answer: Some("11".into()),
+                },
+                part_two: Some(Example {
+                    input: None,
+                    answer: Some("31".into()),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn extracts_separate_examples_of_part_two() {
+        let puzzle = PUZZLE.replace(
+            "Now, count the same list:\n\n```\n3   4\n",
+            "Here is a new example:\n\n```\n5   6\n",
+        );
+
+        let examples = extract(&puzzle);
+        assert_eq!(
+            examples.part_two.unwrap().input,
+            Some("5   6\n4   3\n".into())
+        );
+    }
+
+    #[test]
+    fn falls_back_to_first_block() {
+        let examples = extract("Given:\n\n```\n1\n```\n\nThe answer is *`2`*.\n");
+        assert_eq!(examples.part_one.input, Some("1\n".into()));
+        assert_eq!(examples.part_two, None);
+    }
+
+    #[test]
+    fn finds_last_highlight() {
+        assert_eq!(last_highlight("a *`1`* and *`22`* b"), Some("22".into()));
+        assert_eq!(last_highlight("a `1` b"), None);
+    }
+
+    #[test]
+    fn fills_numeric_answers() {
+        let test = "let result = part_one(input);\n        assert_eq!(result, None);";
+        assert_eq!(
+            fill_answer(test, Some("11")),
+            "let result = part_one(input);\n        assert_eq!(result, Some(11));"
+        );
+        assert_eq!(fill_answer(test, Some("abc")), test);
+        assert_eq!(fill_answer(test, None), test);
+    }
+}
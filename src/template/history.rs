@@ -0,0 +1,426 @@
+/// Keeps a record of every timed run, so the timings of a day can be followed across commits and machines.
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{Error, Write};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tinyjson::JsonValue;
+
+use crate::template::timings::{PartTiming, Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+
+static HISTORY_FILE_PATH: &str = "./data/timings-history.jsonl";
+
+/// The machine a run was benched on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine {
+    pub hostname: String,
+    pub cpu: String,
+}
+
+/// A single timed run, stored as one line of JSON in the history file.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Abbreviated hash of the checked out commit, if run inside a git repository.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    pub machine: Machine,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Records `timings` with the current commit, machine and time.
+    pub fn capture(timings: Timings) -> Self {
+        let commit = git(&["rev-parse", "--short", "HEAD"]);
+        let dirty = git(&["status", "--porcelain", "--untracked-files=no"]).is_some();
+
+        Self {
+            commit,
+            dirty,
+            machine: Machine::current(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+            timings,
+        }
+    }
+}
+
+impl Machine {
+    pub fn current() -> Self {
+        let hostname = fs::read_to_string("/proc/sys/kernel/hostname")
+            .ok()
+            .map(|x| x.trim().to_string())
+            .or_else(|| std::env::var("HOSTNAME").ok())
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .or_else(|| command_output("hostname", &[]))
+            .filter(|x| !x.is_empty())
+            .unwrap_or_else(|| "unknown".into());
+
+        let cpu = fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| {
+                cpuinfo
+                    .lines()
+                    .find(|line| line.starts_with("model name"))
+                    .and_then(|line| line.split_once(':'))
+                    .map(|(_, model)| model.trim().to_string())
+            })
+            .or_else(|| command_output("sysctl", &["-n", "machdep.cpu.brand_string"]))
+            .filter(|x| !x.is_empty())
+            .unwrap_or_else(|| "unknown".into());
+
+        Self { hostname, cpu }
+    }
+}
+
+/// Runs a command and returns its trimmed stdout, if it succeeded and printed anything.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (!stdout.is_empty()).then(|| stdout.to_string())
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    command_output("git", args)
+}
+
+/// Appends a run to the history file, creating it if necessary.
+pub fn append(entry: &HistoryEntry) -> Result<(), Error> {
+    let line = JsonValue::from(entry).stringify().map_err(Error::other)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE_PATH)?;
+
+    writeln!(file, "{line}")
+}
+
+/// Reads all runs from the history file. If not present, the history is empty.
+/// Lines that can not be parsed are skipped with a warning, so one corrupt run does not hide the others.
+pub fn read_from_file() -> Vec<HistoryEntry> {
+    let Ok(contents) = fs::read_to_string(HISTORY_FILE_PATH) else {
+        return vec![];
+    };
+
+    let (entries, skipped) = parse(&contents);
+
+    if !skipped.is_empty() {
+        let lines = skipped
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let noun = if skipped.len() == 1 { "line" } else { "lines" };
+        eprintln!("Warning: skipped unreadable {noun} {lines} of {HISTORY_FILE_PATH}.");
+    }
+
+    entries
+}
+
+/// Parses the history, returning the readable entries and the (1-based) numbers of unreadable lines.
+fn parse(contents: &str) -> (Vec<HistoryEntry>, Vec<usize>) {
+    let mut entries = vec![];
+    let mut skipped = vec![];
+
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry = JsonValue::from_str(line)
+            .ok()
+            .and_then(|json| HistoryEntry::try_from(&json).ok());
+
+        match entry {
+            Some(entry) => entries.push(entry),
+            None => skipped.push(i + 1),
+        }
+    }
+
+    (entries, skipped)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Prints how the timings of `day` evolved, grouped by machine.
+pub fn print_day(entries: &[HistoryEntry], day: Day) {
+    println!("{ANSI_BOLD}Day {day} History{ANSI_RESET}");
+    println!("------");
+
+    let mut machines: Vec<&Machine> = vec![];
+    for entry in entries {
+        if !machines.contains(&&entry.machine) {
+            machines.push(&entry.machine);
+        }
+    }
+
+    let mut is_empty = true;
+
+    for machine in machines {
+        let runs: Vec<_> = entries
+            .iter()
+            .filter(|entry| &entry.machine == machine)
+            .filter_map(|entry| {
+                let timing = entry.timings.data.iter().find(|t| t.day == day)?;
+                Some((entry, timing))
+            })
+            .collect();
+
+        if runs.is_empty() {
+            continue;
+        }
+
+        is_empty = false;
+        println!(
+            "\n{ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({}){ANSI_RESET}",
+            machine.hostname, machine.cpu
+        );
+
+        let mut previous: Option<&Timing> = None;
+
+        for (entry, timing) in runs {
+            let commit = match (&entry.commit, entry.dirty) {
+                (Some(commit), true) => format!("{commit}*"),
+                (Some(commit), false) => commit.clone(),
+                (None, _) => "-".into(),
+            };
+
+            let mut columns = vec![
+                format_timestamp(entry.timestamp),
+                format!("{commit:<10}"),
+                format!(
+                    "{ANSI_ITALIC}{:<6}{ANSI_RESET}",
+                    timing.statistic.to_string()
+                ),
+            ];
+
+            if let Some(parse) = &timing.parse {
+                columns.push(format!("parse {parse}"));
+            }
+
+            for part in [1, 2] {
+                let Some(current) = timing.part(part) else {
+                    continue;
+                };
+                let baseline = previous.and_then(|p| p.part(part));
+                columns.push(format!(
+                    "part {part} {current}{}",
+                    format_delta(baseline, current)
+                ));
+            }
+
+            println!("{}", columns.join("  "));
+            previous = Some(timing);
+        }
+    }
+
+    if is_empty {
+        println!("No recorded runs.");
+    }
+}
+
+fn format_delta(previous: Option<&PartTiming>, current: &PartTiming) -> String {
+    match previous {
+        Some(previous) if previous.nanos > 0 && previous.nanos != current.nanos => {
+            #[allow(clippy::cast_precision_loss)]
+            let delta =
+                (current.nanos as f64 - previous.nanos as f64) / previous.nanos as f64 * 100_f64;
+            format!(" ({delta:+.1}%)")
+        }
+        _ => String::new(),
+    }
+}
+
+/// Formats seconds since the unix epoch as a UTC date and time, e.g. `2025-12-09 14:02`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + u64::from(m <= 2);
+
+    format!(
+        "{y:04}-{m:02}-{d:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("dirty".into(), JsonValue::Boolean(value.dirty));
+        map.insert(
+            "hostname".into(),
+            JsonValue::String(value.machine.hostname.clone()),
+        );
+        map.insert("cpu".into(), JsonValue::String(value.machine.cpu.clone()));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("timings".into(), JsonValue::from(value.timings.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let get_string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected entry.{key} to be a string."))
+        };
+
+        let commit = match json.get("commit") {
+            Some(JsonValue::Null) => None,
+            _ => Some(get_string("commit")?),
+        };
+
+        let dirty = json
+            .get("dirty")
+            .and_then(|v| v.get::<bool>())
+            .copied()
+            .ok_or("Expected entry.dirty to be a boolean.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let timings = json
+            .get("timings")
+            .ok_or("Expected entry to have key `timings`.")
+            .map_err(String::from)
+            .and_then(Timings::try_from)?;
+
+        Ok(HistoryEntry {
+            commit,
+            dirty,
+            machine: Machine {
+                hostname: get_string("hostname")?,
+                cpu: get_string("cpu")?,
+            },
+            timestamp,
+            timings,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{HistoryEntry, Machine, format_timestamp, parse};
+    use crate::{
+        day,
+        template::{
+            stats::Statistic,
+            timings::{PartTiming, Timing, Timings},
+        },
+    };
+
+    fn get_mock_entry() -> HistoryEntry {
+        HistoryEntry {
+            commit: Some("a1b2c3d".into()),
+            dirty: true,
+            machine: Machine {
+                hostname: "box".into(),
+                cpu: "Some CPU @ 3.00GHz".into(),
+            },
+            timestamp: 1_765_289_000,
+            timings: Timings {
+                data: vec![Timing {
+                    day: day!(9),
+                    statistic: Statistic::Median,
+                    parse: None,
+                    part_1: Some(PartTiming {
+                        nanos: 1_500,
+                        samples: Some(10),
+                        allocs: None,
+                        perf: None,
+                    }),
+                    part_2: None,
+                    total_nanos: 1_500_f64,
+                    timed_out: false,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let line = JsonValue::from(&get_mock_entry()).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+
+        let (entries, skipped) = parse(&format!("{line}\n\n{line}\n"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(skipped, vec![]);
+
+        let entry = &entries[0];
+        assert_eq!(entry.commit, Some("a1b2c3d".into()));
+        assert_eq!(entry.dirty, true);
+        assert_eq!(entry.machine, get_mock_entry().machine);
+        assert_eq!(entry.timestamp, 1_765_289_000);
+        assert_eq!(entry.timings.data[0].day, day!(9));
+        assert_eq!(entry.timings.data[0].part_1.unwrap().nanos, 1_500);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let line = JsonValue::from(&get_mock_entry()).stringify().unwrap();
+        let (entries, skipped) = parse(&format!("{line}\n{{}}\n{line}\nnot json\n"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(skipped, vec![2, 4]);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+        assert_eq!(format_timestamp(1_765_289_000), "2025-12-09 14:03");
+    }
+}
@@ -0,0 +1,270 @@
+/// Selects the input that a solution binary runs against.
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+use crate::template::Day;
+
+/// Input of a solution binary, chosen via `--input <path>`, `--example [N]` or `-` for stdin.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/examples`, or the example of a part if given, e.g. `01-2.txt`.
+    Example(Option<u8>),
+    /// A file at an arbitrary path, e.g. a generated stress input.
+    Path(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the input source from the arguments of a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().skip(1).collect();
+
+        Self::parse(&args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}");
+            process::exit(1);
+        })
+    }
+
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut sources = vec![];
+
+        for (i, arg) in args.iter().enumerate() {
+            match arg.as_str() {
+                "-" => sources.push(Self::Stdin),
+                "--input" => match args.get(i + 1) {
+                    Some(path) if !path.starts_with("--") => {
+                        sources.push(Self::Path(PathBuf::from(path)));
+                    }
+                    _ => return Err("expected a path after `--input`.".into()),
+                },
+                "--example" => {
+                    let part = args.get(i + 1).and_then(|x| x.parse::<u8>().ok());
+                    sources.push(Self::Example(part));
+                }
+                _ => {}
+            }
+        }
+
+        match sources.len() {
+            0 => Ok(Self::Puzzle),
+            1 => Ok(sources.remove(0)),
+            _ => Err("only one of `--input`, `--example` and `-` can be used.".into()),
+        }
+    }
+
+    /// Converts the input source into the arguments understood by [`InputSource::from_args`].
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(part)) => vec!["--example".into(), part.to_string()],
+            Self::Path(path) => vec!["--input".into(), path.display().to_string()],
+            Self::Stdin => vec!["-".into()],
+        }
+    }
+
+    /// Whether answers computed from this input are answers to the puzzle, e.g. to submit them.
+    pub fn is_puzzle(&self) -> bool {
+        *self == Self::Puzzle
+    }
+
+    fn path(&self, day: Day) -> Option<PathBuf> {
+        match self {
+            Self::Puzzle => Some(PathBuf::from(format!("data/inputs/{day}.txt"))),
+            Self::Example(None) => Some(PathBuf::from(format!("data/examples/{day}.txt"))),
+            Self::Example(Some(part)) => {
+                Some(PathBuf::from(format!("data/examples/{day}-{part}.txt")))
+            }
+            Self::Path(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+
+    /// Reads the input for `day`, exiting if it can not be read.
+    pub fn read(&self, day: Day) -> String {
+        self.try_read(day).unwrap_or_else(|e| {
+            match self.path(day) {
+                Some(path) => eprintln!("Could not read input file \"{}\": {e}", path.display()),
+                None => eprintln!("Could not read standard input: {e}"),
+            }
+            process::exit(1);
+        })
+    }
+
+    /// Reads the input for `day`.
+    pub fn try_read(&self, day: Day) -> Result<String, io::Error> {
+        match self.path(day) {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "standard input"),
+            Self::Puzzle => write!(f, "the puzzle input"),
+            Self::Example(_) => write!(f, "an example"),
+            Self::Path(path) => write!(f, "\"{}\"", path.display()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An additional input of a day in `data/inputs/<day>/`, e.g. the input of another account.
+#[derive(Clone, Debug)]
+pub struct NamedInput {
+    /// The file name of the input, without extension.
+    pub name: String,
+    pub path: PathBuf,
+    /// Known answers of both parts, read from `<name>.answers` next to the input.
+    pub answers: [Option<String>; 2],
+}
+
+/// Directory of the named inputs of `day`.
+pub fn named_inputs_dir(day: Day) -> PathBuf {
+    PathBuf::from(format!("data/inputs/{day}"))
+}
+
+/// Finds the named inputs of `day`, sorted by name. Days without a directory of inputs have none.
+pub fn named_inputs(day: Day) -> Result<Vec<NamedInput>, String> {
+    let dir = named_inputs_dir(day);
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("could not read \"{}\": {e}", dir.display())),
+    };
+
+    let mut inputs = vec![];
+
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+
+        if path.extension().is_none_or(|x| x != "txt") {
+            continue;
+        }
+
+        let Some(name) = path.file_stem().and_then(|x| x.to_str()) else {
+            continue;
+        };
+
+        let answers = match fs::read_to_string(path.with_extension("answers")) {
+            Ok(contents) => parse_answers(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => [None, None],
+            Err(e) => return Err(format!("could not read answers of \"{name}\": {e}")),
+        };
+
+        inputs.push(NamedInput {
+            name: name.to_string(),
+            path,
+            answers,
+        });
+    }
+
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// Parses an answers file, which has the answer of part one on its first and of part two on its second line.
+/// Unknown answers are left empty or written as `-`.
+fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let mut lines = contents.lines().map(|line| {
+        let line = line.trim();
+        (!line.is_empty() && line != "-").then(|| line.to_string())
+    });
+
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::day;
+
+    use super::{InputSource, parse_answers};
+
+    fn parse(args: &[&str]) -> Result<InputSource, String> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        InputSource::parse(&args)
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse(&[]), Ok(InputSource::Puzzle));
+        assert_eq!(parse(&["--time"]), Ok(InputSource::Puzzle));
+        assert_eq!(parse(&["-"]), Ok(InputSource::Stdin));
+        assert_eq!(parse(&["--example"]), Ok(InputSource::Example(None)));
+        assert_eq!(
+            parse(&["--example", "--time"]),
+            Ok(InputSource::Example(None))
+        );
+        assert_eq!(
+            parse(&["--submit", "1", "--example", "2"]),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(
+            parse(&["--input", "stress.txt"]),
+            Ok(InputSource::Path(PathBuf::from("stress.txt")))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input_sources() {
+        assert_eq!(parse(&["--input"]).is_err(), true);
+        assert_eq!(parse(&["--input", "--time"]).is_err(), true);
+        assert_eq!(parse(&["-", "--example"]).is_err(), true);
+    }
+
+    #[test]
+    fn round_trips_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Stdin,
+            InputSource::Example(None),
+            InputSource::Example(Some(2)),
+            InputSource::Path(PathBuf::from("a/b.txt")),
+        ] {
+            let args = source.to_args();
+            assert_eq!(InputSource::parse(&args), Ok(source));
+        }
+    }
+
+    #[test]
+    fn resolves_paths() {
+        assert_eq!(
+            InputSource::Example(Some(2)).path(day!(3)),
+            Some(PathBuf::from("data/examples/03-2.txt"))
+        );
+        assert_eq!(
+            InputSource::Puzzle.path(day!(3)),
+            Some(PathBuf::from("data/inputs/03.txt"))
+        );
+        assert_eq!(InputSource::Stdin.path(day!(3)), None);
+    }
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers("11\n31\n"),
+            [Some("11".into()), Some("31".into())]
+        );
+        assert_eq!(parse_answers("-\n 31 "), [None, Some("31".into())]);
+        assert_eq!(parse_answers("11"), [Some("11".into()), None]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+}
@@ -1,20 +1,56 @@
 use std::{env, fs};
 
+pub mod allocations;
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod input;
+pub mod perf;
 pub mod runner;
+pub mod stats;
 
 pub use day::*;
+pub use run_multi::Timeouts;
+pub use year::*;
 
+mod answers;
+mod compare;
 mod day;
+mod examples;
+mod history;
 mod readme_benchmarks;
 mod run_multi;
+mod templates;
 mod timings;
+mod watch;
+mod year;
+
+// NOTE: declared here rather than in `solution!`, as the multi-day runner compiles every day into a single binary.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(all(feature = "count-allocs", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: allocations::CountingAlloc = allocations::CountingAlloc;
+
+#[cfg(all(feature = "count-allocs", feature = "dhat-heap"))]
+compile_error!(
+    "features `count-allocs` and `dhat-heap` both install a global allocator, enable only one."
+);
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Creates the parent directory of `path`, e.g. the directory of a year in `data/inputs`.
+pub(crate) fn create_parent_dir(path: &str) -> std::io::Result<()> {
+    match std::path::Path::new(path).parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
@@ -39,29 +75,46 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The generated `main` runs against the puzzle input, unless another input is passed via
+/// `--input <path>`, `--example [N]` or `-` for stdin, see [`input::InputSource`].
+///
+/// The optional `parse = <fn>` parameter declares the parse step shared by both parts, e.g.
+/// `solution!(1, parse = parse)`. It is timed separately, so benchmarks show how much of a
+/// part's time is spent parsing.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $day, [$($parse)?] [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 1 $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $day, [$($parse)?] [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 2 $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $day, [$($parse)?] [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@impl $day:expr, [$($parse:expr)?] $( [$func:expr, $part:expr] )*) => {
+        /// The current day, in the year of this file if it is named like `2024-05.rs`.
+        const DAY: $crate::template::Day = $crate::day!($day).with_year_of_file(file!());
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// Entry point used by the multi-day runner.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            day: DAY,
+            run: |input, options, answers| {
+                use $crate::template::runner::*;
+                vec![
+                    $( execute_parse($parse, input, options), )?
+                    $( execute_part($func, input, DAY, $part, options, answers), )*
+                ]
+            },
+        };
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+            let input = $crate::template::input::InputSource::from_args().read(DAY);
+            $( run_parse($parse, input.as_str()); )?
             $( run_part($func, &input, DAY, $part); )*
         }
     };
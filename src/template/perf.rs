@@ -0,0 +1,394 @@
+/// Reads hardware performance counters of solution parts via Linux' `perf_event_open`.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::sync::Once;
+
+use tinyjson::JsonValue;
+
+use crate::template::timings::parse_integer;
+
+/// Hardware counters of a single run of a part.
+///
+/// A counter is [`None`] if the CPU or the kernel does not provide it, e.g. in many virtual machines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PerfCounters {
+    pub cycles: Option<u64>,
+    pub instructions: Option<u64>,
+    pub branch_misses: Option<u64>,
+    pub cache_misses: Option<u64>,
+}
+
+static WARNING: Once = Once::new();
+
+/// Runs `func`, returning its hardware counters if `enabled` and access is granted by the kernel.
+///
+/// If counters can not be opened, a warning is printed once and the part runs without them.
+pub fn measure<T>(enabled: bool, func: impl FnOnce() -> T) -> (T, Option<PerfCounters>) {
+    if !enabled {
+        return (func(), None);
+    }
+
+    match sys::Counters::open() {
+        Ok(counters) => {
+            counters.enable();
+            let result = func();
+            (result, Some(counters.disable_and_read()))
+        }
+        Err(e) => {
+            WARNING.call_once(|| {
+                eprintln!("Performance counters are not available: {e}");
+                match e.kind() {
+                    ErrorKind::PermissionDenied => eprintln!(
+                        "Hint: access is restricted by `/proc/sys/kernel/perf_event_paranoid`."
+                    ),
+                    ErrorKind::NotFound => {
+                        eprintln!("Hint: the CPU or hypervisor does not expose hardware counters.")
+                    }
+                    _ => {}
+                }
+            });
+            (func(), None)
+        }
+    }
+}
+
+impl PerfCounters {
+    /// Instructions per cycle, if both counters are available.
+    pub fn ipc(&self) -> Option<f64> {
+        match (self.instructions, self.cycles) {
+            #[allow(clippy::cast_precision_loss)]
+            (Some(instructions), Some(cycles)) if cycles > 0 => {
+                Some(instructions as f64 / cycles as f64)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for PerfCounters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut columns = vec![];
+
+        for (name, value) in [
+            ("cycles", self.cycles),
+            ("instructions", self.instructions),
+            ("branch misses", self.branch_misses),
+            ("cache misses", self.cache_misses),
+        ] {
+            if let Some(value) = value {
+                columns.push(format!("{name} {}", format_count(value)));
+            }
+        }
+
+        if let Some(ipc) = self.ipc() {
+            columns.push(format!("IPC {ipc:.2}"));
+        }
+
+        f.write_str(&columns.join(" · "))
+    }
+}
+
+/// Formats a count with a metric suffix, e.g. `1.2M`.
+fn format_count(value: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let float = value as f64;
+
+    match value {
+        0..1_000 => value.to_string(),
+        1_000..1_000_000 => format!("{:.1}k", float / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}M", float / 1e6),
+        _ => format!("{:.1}G", float / 1e9),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl PerfCounters {
+    fn entries(&self) -> [(&'static str, Option<u64>); 4] {
+        [
+            ("cycles", self.cycles),
+            ("instructions", self.instructions),
+            ("branch_misses", self.branch_misses),
+            ("cache_misses", self.cache_misses),
+        ]
+    }
+}
+
+impl From<&PerfCounters> for JsonValue {
+    fn from(value: &PerfCounters) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, counter) in value.entries() {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert(
+                key.into(),
+                counter.map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PerfCounters {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected perf counters to be a JSON object.")?;
+
+        let get = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => parse_integer(v)
+                .map(Some)
+                .ok_or(format!("Expected perf.{key} to be null or an integer.")),
+        };
+
+        Ok(PerfCounters {
+            cycles: get("cycles")?,
+            instructions: get("instructions")?,
+            branch_misses: get("branch_misses")?,
+            cache_misses: get("cache_misses")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::io::Error;
+    use std::mem::size_of;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+    use super::PerfCounters;
+
+    const PERF_TYPE_HARDWARE: u32 = 0;
+
+    const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+    const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+    const PERF_COUNT_HW_CACHE_MISSES: u64 = 3;
+    const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+
+    const PERF_FORMAT_TOTAL_TIME_ENABLED: u64 = 1 << 0;
+    const PERF_FORMAT_TOTAL_TIME_RUNNING: u64 = 1 << 1;
+
+    const ATTR_FLAG_DISABLED: u64 = 1 << 0;
+    const ATTR_FLAG_INHERIT: u64 = 1 << 1;
+    const ATTR_FLAG_EXCLUDE_KERNEL: u64 = 1 << 5;
+    const ATTR_FLAG_EXCLUDE_HV: u64 = 1 << 6;
+
+    const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+
+    const PERF_EVENT_IOC_ENABLE: libc::c_ulong = 0x2400;
+    const PERF_EVENT_IOC_DISABLE: libc::c_ulong = 0x2401;
+    const PERF_EVENT_IOC_RESET: libc::c_ulong = 0x2403;
+
+    /// First published layout of `struct perf_event_attr` (`PERF_ATTR_SIZE_VER0`), which covers all fields used here.
+    #[repr(C)]
+    #[derive(Default)]
+    struct PerfEventAttr {
+        kind: u32,
+        size: u32,
+        config: u64,
+        sample_period: u64,
+        sample_type: u64,
+        read_format: u64,
+        flags: u64,
+        wakeup_events: u32,
+        bp_type: u32,
+        config1: u64,
+    }
+
+    /// One file descriptor per counter, [`None`] for counters that the hardware does not support.
+    pub struct Counters([Option<OwnedFd>; 4]);
+
+    impl Counters {
+        pub fn open() -> Result<Self, Error> {
+            let mut last_error = None;
+
+            let counters = [
+                PERF_COUNT_HW_CPU_CYCLES,
+                PERF_COUNT_HW_INSTRUCTIONS,
+                PERF_COUNT_HW_BRANCH_MISSES,
+                PERF_COUNT_HW_CACHE_MISSES,
+            ]
+            .map(|config| open_counter(config).map_err(|e| last_error = Some(e)).ok());
+
+            match last_error {
+                Some(e) if counters.iter().all(Option::is_none) => Err(e),
+                _ => Ok(Self(counters)),
+            }
+        }
+
+        pub fn enable(&self) {
+            for fd in self.0.iter().flatten() {
+                unsafe {
+                    libc::ioctl(fd.as_raw_fd(), PERF_EVENT_IOC_RESET, 0);
+                    libc::ioctl(fd.as_raw_fd(), PERF_EVENT_IOC_ENABLE, 0);
+                }
+            }
+        }
+
+        pub fn disable_and_read(self) -> PerfCounters {
+            for fd in self.0.iter().flatten() {
+                unsafe {
+                    libc::ioctl(fd.as_raw_fd(), PERF_EVENT_IOC_DISABLE, 0);
+                }
+            }
+
+            let [cycles, instructions, branch_misses, cache_misses] =
+                self.0.map(|fd| fd.and_then(|fd| read_counter(&fd)));
+
+            PerfCounters {
+                cycles,
+                instructions,
+                branch_misses,
+                cache_misses,
+            }
+        }
+    }
+
+    fn open_counter(config: u64) -> Result<OwnedFd, Error> {
+        let attr = PerfEventAttr {
+            kind: PERF_TYPE_HARDWARE,
+            #[allow(clippy::cast_possible_truncation)]
+            size: size_of::<PerfEventAttr>() as u32,
+            config,
+            read_format: PERF_FORMAT_TOTAL_TIME_ENABLED | PERF_FORMAT_TOTAL_TIME_RUNNING,
+            // NOTE: user space only, which unprivileged processes are allowed to count by default.
+            flags: ATTR_FLAG_DISABLED
+                | ATTR_FLAG_INHERIT
+                | ATTR_FLAG_EXCLUDE_KERNEL
+                | ATTR_FLAG_EXCLUDE_HV,
+            ..Default::default()
+        };
+
+        // measure the calling thread (and threads it spawns) on any CPU.
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_perf_event_open,
+                &raw const attr,
+                0,
+                -1,
+                -1,
+                PERF_FLAG_FD_CLOEXEC,
+            )
+        };
+
+        if fd < 0 {
+            return Err(Error::last_os_error());
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(unsafe { OwnedFd::from_raw_fd(fd as i32) })
+    }
+
+    /// Reads a counter, scaling it up if the kernel had to multiplex it with other counters.
+    fn read_counter(fd: &OwnedFd) -> Option<u64> {
+        let mut values = [0_u64; 3];
+
+        let bytes = unsafe {
+            libc::read(
+                fd.as_raw_fd(),
+                values.as_mut_ptr().cast(),
+                size_of::<[u64; 3]>(),
+            )
+        };
+
+        if usize::try_from(bytes).ok()? != size_of::<[u64; 3]>() {
+            return None;
+        }
+
+        let [value, enabled, running] = values;
+
+        match running {
+            0 => None,
+            _ if running == enabled => Some(value),
+            #[allow(clippy::cast_possible_truncation)]
+            _ => Some((u128::from(value) * u128::from(enabled) / u128::from(running)) as u64),
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use std::io::{Error, ErrorKind};
+
+    use super::PerfCounters;
+
+    pub struct Counters;
+
+    impl Counters {
+        pub fn open() -> Result<Self, Error> {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "only supported on Linux",
+            ))
+        }
+
+        pub fn enable(&self) {}
+
+        pub fn disable_and_read(self) -> PerfCounters {
+            PerfCounters::default()
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PerfCounters, format_count, measure};
+
+    #[test]
+    fn formats_counters() {
+        let counters = PerfCounters {
+            cycles: Some(2_000_000),
+            instructions: Some(5_000_000),
+            branch_misses: Some(1_234),
+            cache_misses: None,
+        };
+
+        assert_eq!(
+            counters.to_string(),
+            "cycles 2.0M · instructions 5.0M · branch misses 1.2k · IPC 2.50"
+        );
+    }
+
+    #[test]
+    fn roundtrips_counters() {
+        let counters = PerfCounters {
+            cycles: Some(2_000_000),
+            instructions: None,
+            branch_misses: Some(0),
+            cache_misses: Some(17),
+        };
+
+        let json = tinyjson::JsonValue::from(&counters);
+        assert_eq!(PerfCounters::try_from(&json), Ok(counters));
+    }
+
+    #[test]
+    fn formats_counts() {
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_500), "1.5k");
+        assert_eq!(format_count(2_500_000_000), "2.5G");
+    }
+
+    #[test]
+    fn skips_counters_if_disabled() {
+        let (result, counters) = measure(false, || 42);
+        assert_eq!(result, 42);
+        assert_eq!(counters, None);
+    }
+
+    #[test]
+    fn degrades_gracefully() {
+        // either counters are available or the part runs without them, it must never fail.
+        let (result, _) = measure(true, || (0..1000).sum::<u64>());
+        assert_eq!(result, 499_500);
+    }
+}
@@ -2,8 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::allocations::{AllocStats, format_bytes};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -27,7 +28,7 @@ pub struct TablePosition {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/bin/{}.rs", day.bin_name())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,33 +53,110 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
+/// Constructs the benchmark tables. Days of other years than the one set via `AOC_YEAR` get a table per year.
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut years: Vec<Option<Year>> = timings.data.iter().map(|t| t.day.year()).collect();
+    years.sort_unstable();
+    years.dedup();
 
-    for timing in timings.data {
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    // NOTE: days of the year set via `AOC_YEAR` alone keep a single table without a heading for the year.
+    if years.iter().all(Option::is_none) {
+        lines.push(String::new());
+        lines.extend(construct_year_table(&timings.data));
+        lines.push(String::new());
+        lines.push(format!("**Total: {total_millis:.2}ms**"));
+    } else {
+        for year in years {
+            let data: Vec<Timing> = timings
+                .data
+                .iter()
+                .filter(|t| t.day.year() == year)
+                .cloned()
+                .collect();
+
+            let label = year
+                .or_else(Year::configured)
+                .map_or_else(|| "Current year".into(), |year| year.to_string());
+
+            let total_millis = Timings { data: data.clone() }.total_millis();
+
+            lines.push(String::new());
+            lines.push(format!("{prefix}# {label}"));
+            lines.push(String::new());
+            lines.extend(construct_year_table(&data));
+            lines.push(String::new());
+            lines.push(format!("**Total: {total_millis:.2}ms**"));
+        }
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+/// Constructs the rows of a table of timings, including its header.
+fn construct_year_table(timings: &[Timing]) -> Vec<String> {
+    // heap usage is only recorded when the runner counts allocations.
+    let has_allocs = timings.iter().any(|t| {
+        [&t.part_1, &t.part_2]
+            .iter()
+            .any(|p| p.is_some_and(|p| p.allocs.is_some()))
+    });
+
+    let mut lines: Vec<String> = vec![];
+
+    if has_allocs {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Allocations | Peak heap |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
+
+    for timing in timings {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let missing = if timing.timed_out { "timed out" } else { "-" };
+        let mut line = format!(
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            timing.parse.map_or_else(|| "-".into(), |x| x.to_string()),
+            timing
+                .part_1
+                .map_or_else(|| missing.into(), |x| x.to_string()),
+            timing
+                .part_2
+                .map_or_else(|| missing.into(), |x| x.to_string())
+        );
+
+        if has_allocs {
+            line.push_str(&format!(
+                " `{}` | `{}` |",
+                format_parts(timing, |a| a.allocations.to_string()),
+                format_parts(timing, |a| format_bytes(a.peak_bytes))
+            ));
+        }
+
+        lines.push(line);
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines
+}
 
-    lines.join("\n")
+/// Formats the heap usage of both parts as `<part 1> / <part 2>`.
+fn format_parts(timing: &Timing, format: impl Fn(&AllocStats) -> String) -> String {
+    [&timing.part_1, &timing.part_2]
+        .iter()
+        .map(|part| {
+            part.and_then(|p| p.allocs)
+                .map_or_else(|| "-".into(), |a| format(&a))
+        })
+        .collect::<Vec<_>>()
+        .join(" / ")
 }
 
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
@@ -88,7 +166,10 @@ fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(mut timings: Timings) -> Result<(), Error> {
+    // stored timings may contain days that are not part of the event, e.g. the 13th of 2025.
+    timings.data.retain(|t| t.day.is_in_event());
+
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
@@ -100,28 +181,52 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::Year,
+        template::allocations::AllocStats,
+        template::stats::Statistic,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    fn ms(millis: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1_000_000,
+            samples: Some(10),
+            allocs: None,
+            perf: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: ms(1),
+                    part_1: ms(10),
+                    part_2: ms(20),
                     total_nanos: 3e+10,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(30),
+                    part_2: ms(40),
                     total_nanos: 7e+10,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(40),
+                    part_2: ms(50),
                     total_nanos: 9e+10,
+                    timed_out: false,
                 },
             ],
         }
@@ -167,11 +272,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `1.0ms` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +285,69 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_timed_out_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_2 = None;
+        timings.data[1].timed_out = true;
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `-` | `30.0ms` | `timed out` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_allocations() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1 = Some(PartTiming {
+            allocs: Some(AllocStats {
+                allocations: 12,
+                bytes: 4096,
+                peak_bytes: 1536,
+            }),
+            perf: None,
+            ..timings.data[0].part_1.unwrap()
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| Day | Parse | Part 1 | Part 2 | Allocations | Peak heap |"),
+            true
+        );
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/01.rs) | `1.0ms` | `10.0ms` | `20.0ms` | `12 / -` | `1.5 KiB / -` |"
+            ),
+            true
+        );
+        assert_eq!(
+            s.contains(
+                "| [Day 2](./src/bin/02.rs) | `-` | `30.0ms` | `40.0ms` | `- / -` | `- / -` |"
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_per_year() {
+        let mut timings = get_mock_timings();
+        timings.data[2].day = day!(4).with_year(Year::new(2016).unwrap());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+
+        assert_eq!(s.matches("## Benchmarks").count(), 1);
+        assert_eq!(s.contains("### 2016"), true);
+        assert_eq!(
+            s.contains("| [Day 4](./src/bin/2016-04.rs) | `-` | `40.0ms` | `50.0ms` |"),
+            true
+        );
+        assert_eq!(s.contains("**Total: 100000.00ms**"), true);
+        assert_eq!(s.contains("**Total: 90000.00ms**"), true);
+    }
 }
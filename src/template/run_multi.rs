@@ -1,48 +1,239 @@
-use std::{collections::HashSet, io};
+use std::time::{Duration, Instant};
+use std::{collections::HashSet, fs, io};
 
+use crate::template::answers::Answers;
+use crate::template::input::InputSource;
+use crate::template::runner::{PARSE_STEP, PartResult, RunOptions, Solution, find_solution};
 use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
 use super::{
-    all_days,
-    timings::{Timing, Timings},
+    stats::Statistic,
+    timings::{PartTiming, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// How solutions are invoked by [`run_multi`].
+#[derive(Clone, Copy)]
+pub enum RunMode<'a> {
+    /// Call the part functions of the registered solutions directly.
+    InProcess(&'a [Solution]),
+    /// Run the binary of every day in its own process, e.g. to survive a panicking solution.
+    Isolated {
+        is_release: bool,
+        timeouts: Timeouts,
+    },
+}
+
+/// Limits on how long solutions may run. Only isolated runs can be stopped, see [`RunMode::Isolated`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timeouts {
+    /// Limit for each day.
+    pub day: Option<Duration>,
+    /// Limit for all days together.
+    pub total: Option<Duration>,
+}
+
+impl Timeouts {
+    pub fn is_set(&self) -> bool {
+        self.day.is_some() || self.total.is_some()
+    }
+
+    /// Time that the next day may run for, after days already ran for `elapsed`.
+    fn remaining(&self, elapsed: Duration) -> Option<Duration> {
+        let total = self.total.map(|total| total.saturating_sub(elapsed));
+
+        match (self.day, total) {
+            (Some(day), Some(total)) => Some(day.min(total)),
+            (day, total) => day.or(total),
+        }
+    }
+}
 
+/// Results of running all parts of a single day.
+#[derive(Clone, Debug)]
+pub struct DayResult {
+    pub day: Day,
+    pub parts: Vec<PartResult>,
+    /// Whether the day was stopped before all parts finished.
+    pub timed_out: bool,
+}
+
+/// Run the given days, print their results and return the results of all solved days.
+///
+/// Days that time out are reported as well, with the results of parts that finished in time.
+/// If `answers` are passed, every part is marked with whether it matches its accepted answer.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    mode: RunMode,
+    options: RunOptions,
+    answers: Option<&Answers>,
+) -> Vec<DayResult> {
+    let mut day_results: Vec<DayResult> = Vec::with_capacity(days_to_run.len());
+
+    let executables = match mode {
+        RunMode::InProcess(_) => Default::default(),
+        RunMode::Isolated { is_release, .. } => {
+            child_commands::build_solutions(days_to_run, is_release).unwrap()
+        }
+    };
+
+    let started = Instant::now();
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let mut days: Vec<Day> = days_to_run.iter().copied().collect();
+    days.sort_unstable();
+
+    days.into_iter().for_each(|day| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let timeout = match mode {
+            RunMode::InProcess(_) => None,
+            RunMode::Isolated { timeouts, .. } => timeouts.remaining(started.elapsed()),
+        };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        let result = match (mode, executables.get(&day)) {
+            (RunMode::InProcess(solutions), _) => run_in_process(solutions, day, options, answers),
+            (RunMode::Isolated { .. }, Some(_)) if timeout.is_some_and(|t| t.is_zero()) => {
+                DayResult {
+                    day,
+                    parts: vec![],
+                    timed_out: true,
+                }
             }
-        });
+            (RunMode::Isolated { .. }, Some(executable)) => child_commands::run_solution(
+                executable,
+                day,
+                &InputSource::Puzzle,
+                options,
+                timeout,
+                answers,
+            )
+            .unwrap(),
+            (RunMode::Isolated { .. }, None) => DayResult {
+                day,
+                parts: vec![],
+                timed_out: false,
+            },
+        };
 
-    if is_timed {
-        let timings = Timings { data: timings };
+        if result.timed_out {
+            match timeout {
+                Some(timeout) if !timeout.is_zero() => {
+                    println!("{ANSI_BOLD}Timed out{ANSI_RESET} after {timeout:.1?}.");
+                }
+                _ => {
+                    println!("{ANSI_BOLD}Skipped{ANSI_RESET}, the total timeout is exhausted.")
+                }
+            }
+            day_results.push(result);
+        } else if result.parts.is_empty() {
+            println!("Not solved.");
+        } else {
+            day_results.push(result);
+        }
+    });
+
+    if options.is_timed {
+        let timings = Timings {
+            data: day_results
+                .iter()
+                .map(|result| result.timing(options.statistic))
+                .collect(),
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+    }
+
+    day_results
+}
+
+/// Runs a day in-process. When verifying against `answers`, parts are marked as they are printed.
+fn run_in_process(
+    solutions: &[Solution],
+    day: Day,
+    options: RunOptions,
+    answers: Option<&Answers>,
+) -> DayResult {
+    let mut result = DayResult {
+        day,
+        parts: vec![],
+        timed_out: false,
+    };
+
+    let Some(solution) = find_solution(solutions, day) else {
+        return result;
+    };
+
+    let Ok(input) = fs::read_to_string(get_path_for_input(day)) else {
+        eprintln!("Could not read input file \"{}\".", get_path_for_input(day));
+        return result;
+    };
+
+    result.parts = (solution.run)(&input, options, answers);
+    result
+}
+
+impl DayResult {
+    /// Collects the timings of solved parts. Unsolved parts are left empty.
+    ///
+    /// The parse step is already contained in the part timings and does not add to the total.
+    pub fn timing(&self, statistic: Statistic) -> Timing {
+        Timing {
+            timed_out: self.timed_out,
+            ..timing_from_results(self.day, &self.parts, statistic)
+        }
+    }
+}
+
+fn timing_from_results(day: Day, results: &[PartResult], statistic: Statistic) -> Timing {
+    let mut timing = Timing {
+        day,
+        statistic,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        timed_out: false,
+    };
+
+    if let Some(result) = results.iter().find(|result| result.part == PARSE_STEP) {
+        timing.parse = Some(PartTiming::from(result));
+    }
+
+    for result in results.iter().filter(|result| result.answer.is_some()) {
+        let part_timing = Some(PartTiming::from(result));
+
+        match result.part {
+            1 => timing.part_1 = part_timing,
+            2 => timing.part_2 = part_timing,
+            _ => continue,
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timing.total_nanos += result.duration.as_nanos() as f64;
+        }
+    }
+
+    timing
+}
+
+impl From<&PartResult> for PartTiming {
+    fn from(result: &PartResult) -> Self {
+        PartTiming {
+            nanos: u64::try_from(result.duration.as_nanos()).unwrap_or(u64::MAX),
+            samples: u64::try_from(result.samples).ok(),
+            allocs: result.allocs,
+            perf: result.perf,
+        }
     }
 }
 
@@ -61,45 +252,126 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/bin/{}.rs", day.bin_name())
+}
+
+#[must_use]
+pub fn get_path_for_input(day: Day) -> String {
+    format!("./data/inputs/{day}.txt")
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as parsing their results.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{DayResult, Error, get_path_for_bin};
+    use crate::template::{
+        Day,
+        answers::Answers,
+        input::InputSource,
+        runner::{PartResult, RunOptions, print_part_result},
+    };
     use std::{
+        collections::{HashMap, HashSet},
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        str::FromStr,
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Build the solution bins of the given days, returning the path of every executable that was built.
+    ///
+    /// Binaries are built up front and run directly, so a timeout can kill the solution itself
+    /// instead of a `cargo run` wrapper and compile time does not count against it.
+    pub fn build_solutions(
+        days: &HashSet<Day>,
+        is_release: bool,
+    ) -> Result<HashMap<Day, PathBuf>, Error> {
+        // skip days that have not been scaffolded yet.
+        let bins: Vec<String> = days
+            .iter()
+            .filter(|day| Path::new(&get_path_for_bin(**day)).exists())
+            .map(|day| day.bin_name())
+            .collect();
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if bins.is_empty() {
+            return Ok(HashMap::new());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--keep-going",
+            "--message-format=json-render-diagnostics",
+        ];
+
+        for bin in &bins {
+            args.extend(["--bin", bin]);
+        }
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        // children need the counting allocator as well to report heap usage.
+        if cfg!(feature = "count-allocs") {
+            args.extend(["--features", "count-allocs"]);
+        }
+
+        // NOTE: compiler errors are rendered to stderr, stdout only carries build messages.
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_artifact)
+            .collect())
+    }
+
+    /// Parse a build message, if it announces the executable of a solution bin.
+    pub fn parse_artifact(line: &str) -> Option<(Day, PathBuf)> {
+        let json = JsonValue::from_str(line).ok()?;
+        let json = json.get::<HashMap<String, JsonValue>>()?;
+
+        if json.get("reason")?.get::<String>()? != "compiler-artifact" {
+            return None;
         }
 
+        let target = json.get("target")?.get::<HashMap<String, JsonValue>>()?;
+        let day = Day::from_str(target.get("name")?.get::<String>()?).ok()?;
+        let executable = json.get("executable")?.get::<String>()?;
+
+        Some((day, PathBuf::from(executable)))
+    }
+
+    /// Run the solution bin for a given day against `input`, killing it once `timeout` elapses.
+    ///
+    /// Results that the bin reported before it was killed are kept. Parts are marked as verified against `answers`, if passed.
+    /// With [`RunOptions::quiet`], results are collected without printing them.
+    pub fn run_solution(
+        executable: &Path,
+        day: Day,
+        input: &InputSource,
+        options: RunOptions,
+        timeout: Option<Duration>,
+        answers: Option<&Answers>,
+    ) -> Result<DayResult, Error> {
+        // request machine-readable results from the child.
+        let mut args = vec!["--json".to_string()];
+
+        // mirror `--time` and bench options to child invocations.
+        args.extend(options.to_args());
+        args.extend(input.to_args());
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while collecting results.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,150 +380,379 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stderr_thread = thread::spawn(move || {
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
-    }
-
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+        // NOTE: lines are read on a separate thread, so waiting for them can time out.
+        let (sender, receiver) = mpsc::channel();
+        let stdout_thread = thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
                 }
+            }
+        });
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut parts = vec![];
+        let mut timed_out = false;
+
+        loop {
+            let line = match deadline {
+                Some(deadline) => {
+                    match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(line) => line,
+                        Err(RecvTimeoutError::Disconnected) => break,
+                        Err(RecvTimeoutError::Timeout) => {
+                            timed_out = true;
+                            cmd.kill()?;
+                            break;
+                        }
+                    }
                 }
+                None => match receiver.recv() {
+                    Ok(line) => line,
+                    Err(_) => break,
+                },
+            };
+
+            match parse_result(&line) {
+                Some(result) => {
+                    if !options.quiet {
+                        let verified = answers
+                            .and_then(|x| x.verify(day, result.part, result.answer.as_deref()));
+                        print_part_result(&result, verified);
+                    }
+                    parts.push(result);
+                }
+                // anything else was printed by the solution itself.
+                None => println!("{line}"),
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
-
-        timings
-    }
+        cmd.wait()?;
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+        Ok(DayResult {
+            day,
+            parts,
+            timed_out,
+        })
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
+    /// Parse a line of child output, if it is a result emitted by `run_part` in `--json` mode.
+    pub fn parse_result(line: &str) -> Option<PartResult> {
+        if !line.starts_with('{') {
+            return None;
+        }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+        PartResult::try_from(line).ok()
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::time::Duration;
+
+        use std::path::PathBuf;
 
+        use super::{parse_artifact, parse_result};
         use crate::day;
+        use crate::template::{allocations::AllocStats, runner::PartResult, stats::BenchStats};
+        use tinyjson::JsonValue;
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_artifacts() {
+            let line = r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"05"},"executable":"/repo/target/release/05","fresh":true}"#;
+            assert_eq!(
+                parse_artifact(line),
+                Some((day!(5), PathBuf::from("/repo/target/release/05")))
+            );
+
+            let lib = r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"advent_of_code"},"executable":null}"#;
+            assert_eq!(parse_artifact(lib), None);
+            assert_eq!(
+                parse_artifact(r#"{"reason":"build-finished","success":true}"#),
+                None
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_results() {
+            let res = parse_result(
+                r#"{"part":2,"status":"solved","answer":"10","nanos":74130000,"samples":99999}"#,
+            )
+            .unwrap();
+            assert_eq!(res.part, 2);
+            assert_eq!(res.answer.unwrap(), "10");
+            assert_eq!(res.duration, Duration::from_nanos(74_130_000));
+            assert_eq!(res.samples, 99999);
+        }
+
+        #[test]
+        fn parses_with_patterns_in_answer() {
+            let res = parse_result(
+                r#"{"part":1,"status":"solved","answer":"(2s @ 5 samples)\n{}","nanos":2,"samples":1}"#,
+            )
+            .unwrap();
+            assert_eq!(res.answer.unwrap(), "(2s @ 5 samples)\n{}");
+            assert_eq!(res.duration, Duration::from_nanos(2));
+        }
+
+        #[test]
+        fn parses_unsolved_parts() {
+            let res = parse_result(
+                r#"{"part":1,"status":"unsolved","answer":null,"nanos":0,"samples":1}"#,
+            )
+            .unwrap();
+            assert_eq!(res.answer.is_none(), true);
+        }
+
+        #[test]
+        fn ignores_solution_output() {
+            assert_eq!(
+                parse_result("Part 1: 0 (74.13ns @ 100000 samples)").is_none(),
+                true
+            );
+            assert_eq!(parse_result("{ debug output }").is_none(), true);
+            assert_eq!(parse_result(r#"{"part":1}"#).is_none(), true);
+            assert_eq!(
+                parse_result(r#"{"part":1.5,"status":"unsolved","nanos":0,"samples":1}"#).is_none(),
+                true
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn roundtrips_results() {
+            let result = PartResult {
+                part: 1,
+                answer: Some("multi\nline".into()),
+                error: None,
+                duration: Duration::from_nanos(123_456_789),
+                samples: 10000,
+                stats: BenchStats::from_samples(&[
+                    Duration::from_nanos(120_000_000),
+                    Duration::from_nanos(123_456_789),
+                ]),
+                allocs: Some(AllocStats {
+                    allocations: 3,
+                    bytes: 4096,
+                    peak_bytes: 2048,
+                }),
+                perf: None,
+            };
+            let line = JsonValue::from(&result).stringify().unwrap();
+            let res = parse_result(&line).unwrap();
+            assert_eq!(res.answer, result.answer);
+            assert_eq!(res.duration, result.duration);
+            assert_eq!(res.samples, result.samples);
+            assert_eq!(res.stats, result.stats);
+            assert_eq!(res.allocs, result.allocs);
+        }
+
+        #[test]
+        fn roundtrips_failed_results() {
+            let result = PartResult {
+                part: 2,
+                answer: None,
+                error: Some("line 3, column 1: expected a digit, found `x`".into()),
+                duration: Duration::from_nanos(10),
+                samples: 1,
+                stats: None,
+                allocs: None,
+                perf: None,
+            };
+            let line = JsonValue::from(&result).stringify().unwrap();
+            let res = parse_result(&line).unwrap();
+            assert_eq!(res.answer, None);
+            assert_eq!(res.error, result.error);
+            assert_eq!(
+                parse_result(r#"{"part":1,"status":"failed","nanos":0,"samples":1}"#).is_none(),
+                true
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    use std::str::FromStr;
+
+    use super::{DayResult, Timeouts, timing_from_results};
+    use crate::{
+        day,
+        template::{
+            Day, Year,
+            runner::{PartResult, RunOptions, Solution, execute_part, find_solution},
+            stats::Statistic,
+            timings::PartTiming,
+        },
+    };
+
+    #[test]
+    fn finds_solutions_of_files_of_the_configured_year() {
+        // NOTE: cargo sets `AOC_YEAR` via `.cargo/config.toml`.
+        let year = Year::configured().unwrap();
+        let solutions = [Solution {
+            day: day!(5).with_year_of_file(&format!("/repo/src/bin/{year}-05.rs")),
+            run: |_, _, _| vec![],
+        }];
+
+        let day = Day::from_str(&format!("{year}/05")).unwrap();
+        assert_eq!(find_solution(&solutions, day).is_some(), true);
+        assert_eq!(find_solution(&solutions, day!(5)).is_some(), true);
+    }
+
+    #[test]
+    fn limits_days_by_both_timeouts() {
+        let timeouts = Timeouts {
+            day: Some(Duration::from_secs(10)),
+            total: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(
+            timeouts.remaining(Duration::from_secs(5)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            timeouts.remaining(Duration::from_secs(55)),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            timeouts.remaining(Duration::from_secs(70)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(Timeouts::default().remaining(Duration::MAX), None);
+        assert_eq!(Timeouts::default().is_set(), false);
+    }
+
+    #[test]
+    fn marks_timed_out_days() {
+        let result = DayResult {
+            day: day!(1),
+            parts: vec![PartResult {
+                part: 1,
+                answer: Some("0".into()),
+                error: None,
+                duration: Duration::from_nanos(74),
+                samples: 1,
+                stats: None,
+                allocs: None,
+                perf: None,
+            }],
+            timed_out: true,
+        };
+
+        let timing = result.timing(Statistic::Mean);
+        assert_eq!(timing.timed_out, true);
+        assert_eq!(timing.part_1.is_some(), true);
+        assert_eq!(timing.part_2, None);
+    }
+
+    #[test]
+    fn collects_timings_from_results() {
+        let res = timing_from_results(
+            day!(1),
+            &[
+                PartResult {
+                    part: 1,
+                    answer: Some("0".into()),
+                    error: None,
+                    duration: Duration::from_nanos(74),
+                    samples: 100_000,
+                    stats: None,
+                    allocs: None,
+                    perf: None,
+                },
+                PartResult {
+                    part: 2,
+                    answer: Some("10".into()),
+                    error: None,
+                    duration: Duration::from_micros(74_130),
+                    samples: 99_999,
+                    stats: None,
+                    allocs: None,
+                    perf: None,
+                },
+            ],
+            Statistic::Median,
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.statistic, Statistic::Median);
+        assert_eq!(
+            res.part_1.unwrap(),
+            PartTiming {
+                nanos: 74,
+                samples: Some(100_000),
+                allocs: None,
+                perf: None,
+            }
+        );
+        assert_eq!(
+            res.part_2.unwrap(),
+            PartTiming {
+                nanos: 74_130_000,
+                samples: Some(99_999),
+                allocs: None,
+                perf: None,
+            }
+        );
+        assert_eq!(res.part_2.unwrap().to_string(), "74.1ms");
+    }
+
+    #[test]
+    fn does_not_bench_failed_parts() {
+        let runs = Cell::new(0);
+        let options = RunOptions {
+            is_timed: true,
+            quiet: true,
+            ..RunOptions::default()
+        };
+
+        let result = execute_part(
+            |_: &str| {
+                runs.set(runs.get() + 1);
+                Err::<u32, _>("malformed input")
+            },
+            "",
+            day!(1),
+            1,
+            options,
+            None,
+        );
+
+        assert_eq!(runs.get(), 1);
+        assert_eq!(result.error, Some("malformed input".into()));
+        assert_eq!(result.stats.is_none(), true);
+
+        let timing = timing_from_results(day!(1), &[result], Statistic::Mean);
+        assert_eq!(timing.part_1, None);
+    }
+
+    #[test]
+    fn skips_unsolved_parts() {
+        let res = timing_from_results(
+            day!(1),
+            &[PartResult {
+                part: 1,
+                answer: None,
+                error: None,
+                duration: Duration::from_millis(1),
+                samples: 1,
+                stats: None,
+                allocs: None,
+                perf: None,
+            }],
+            Statistic::Mean,
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
+}
@@ -1,60 +1,559 @@
 /// Encapsulates code that interacts with solution functions.
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use tinyjson::JsonValue;
+
 use crate::template::ANSI_BOLD;
+use crate::template::allocations::{self, AllocStats};
+use crate::template::answers::{Answers, Check, Verdict};
+use crate::template::aoc_client::{
+    AocClient, AocClientError, Backend, Submission, parse_cli_submission,
+};
+use crate::template::input::InputSource;
+use crate::template::perf::{self, PerfCounters};
+use crate::template::stats::{BenchStats, Statistic};
+use crate::template::timings::parse_integer;
 use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Entry point of a single day, as registered with the multi-day runner.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: Day,
+    /// Runs all parts of the day against `input`. Printed parts are marked with whether they match their accepted
+    /// answer in `answers`, if passed.
+    pub run: fn(input: &str, options: RunOptions, answers: Option<&Answers>) -> Vec<PartResult>,
+}
+
+/// Looks up the solution of `day` in a registry of solutions.
+pub fn find_solution(solutions: &[Solution], day: Day) -> Option<&Solution> {
+    solutions.iter().find(|solution| solution.day == day)
+}
+
+/// Options that control how solution parts are run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// Bench parts instead of running them once.
+    pub is_timed: bool,
+    /// Number of iterations that are run and discarded before benching.
+    pub warmup: u32,
+    /// The statistic of the bench samples that is reported as a part's timing.
+    pub statistic: Statistic,
+    /// Read hardware performance counters of every part.
+    pub perf: bool,
+    /// Collect results without printing them, so the caller can print them with more context.
+    pub quiet: bool,
+}
+
+impl RunOptions {
+    /// Read options from the arguments of a solution binary, e.g. `--time --warmup 5 --stat median`.
+    pub fn from_args() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+
+        Self::parse(&mut args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}");
+            process::exit(1);
+        })
+    }
+
+    fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            is_timed: args.contains("--time"),
+            warmup: args.opt_value_from_str("--warmup")?.unwrap_or_default(),
+            statistic: args.opt_value_from_str("--stat")?.unwrap_or_default(),
+            perf: args.contains("--perf"),
+            quiet: false,
+        })
+    }
+
+    /// Converts options into the arguments understood by [`RunOptions::from_args`].
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.is_timed {
+            args.extend([
+                "--time".into(),
+                "--warmup".into(),
+                self.warmup.to_string(),
+                "--stat".into(),
+                self.statistic.to_string(),
+            ]);
+        }
+
+        if self.perf {
+            args.push("--perf".into());
+        }
+
+        args
+    }
+}
+
+/// Value of [`PartResult::part`] for the parse step of a solution.
+pub const PARSE_STEP: u8 = 0;
+
+/// Outcome of running a single part, or the parse step, of a solution.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    /// The part number, or [`PARSE_STEP`].
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The error a part failed with, e.g. on malformed input. Failed parts have no answer.
+    pub error: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Statistics of the bench samples, if the part was timed.
+    pub stats: Option<BenchStats>,
+    /// Heap usage of a single run, if the runner counts allocations.
+    pub allocs: Option<AllocStats>,
+    /// Hardware counters of a single run, if requested and available.
+    pub perf: Option<PerfCounters>,
+}
+
+impl PartResult {
+    /// The answer of the part, or the error it failed with.
+    pub fn outcome(&self) -> Result<Option<String>, String> {
+        match &self.error {
+            Some(error) => Err(error.clone()),
+            None => Ok(self.answer.clone()),
+        }
+    }
+}
+
+/// Return types of solution parts, which are either `Option<T>` or `Result<T, E>`.
+///
+/// Parts that return a `Result` fail with the message of their error instead of panicking, e.g. a
+/// [`ParseError`](crate::parse::ParseError) that names the line and column of malformed input.
+pub trait PartOutput {
+    /// The answer, `Ok(None)` if there is none yet, or the message of the error the part failed with.
+    fn answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+
+        let (status, answer) = match value.outcome() {
+            Ok(Some(answer)) => ("solved", JsonValue::String(answer)),
+            Ok(None) => ("unsolved", JsonValue::Null),
+            Err(error) => {
+                map.insert("error".into(), JsonValue::String(error));
+                ("failed", JsonValue::Null)
+            }
+        };
+
+        map.insert("status".into(), JsonValue::String(status.into()));
+        map.insert("answer".into(), answer);
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        map.insert(
+            "stats".into(),
+            value.stats.map_or(JsonValue::Null, |stats| {
+                let mut stats_map: HashMap<String, JsonValue> = HashMap::new();
+
+                for (key, duration) in [
+                    ("min", stats.min),
+                    ("median", stats.median),
+                    ("mean", stats.mean),
+                    ("stddev", stats.stddev),
+                    ("p95", stats.p95),
+                    ("p99", stats.p99),
+                ] {
+                    #[allow(clippy::cast_precision_loss)]
+                    stats_map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+                }
+
+                JsonValue::Object(stats_map)
+            }),
+        );
+
+        map.insert(
+            "allocs".into(),
+            value.allocs.map_or(JsonValue::Null, |allocs| {
+                let mut allocs_map: HashMap<String, JsonValue> = HashMap::new();
+
+                for (key, value) in [
+                    ("allocations", allocs.allocations),
+                    ("bytes", allocs.bytes),
+                    ("peak_bytes", allocs.peak_bytes),
+                ] {
+                    #[allow(clippy::cast_precision_loss)]
+                    allocs_map.insert(key.into(), JsonValue::Number(value as f64));
+                }
+
+                JsonValue::Object(allocs_map)
+            }),
+        );
+
+        map.insert(
+            "perf".into(),
+            value
+                .perf
+                .map_or(JsonValue::Null, |perf| JsonValue::from(&perf)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(parse_integer)
+            .and_then(|v| u8::try_from(v).ok())
+            .ok_or("Expected result.part to be an integer.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.status to be a string.")?;
+
+        let (answer, error) = match status.as_str() {
+            "solved" => (
+                Some(
+                    json.get("answer")
+                        .and_then(|v| v.get::<String>())
+                        .cloned()
+                        .ok_or("Expected result.answer to be a string.")?,
+                ),
+                None,
+            ),
+            "unsolved" => (None, None),
+            "failed" => (
+                None,
+                Some(
+                    json.get("error")
+                        .and_then(|v| v.get::<String>())
+                        .cloned()
+                        .ok_or("Expected result.error to be a string.")?,
+                ),
+            ),
+            _ => return Err(format!("Unknown result.status `{status}`.")),
+        };
+
+        let nanos = json
+            .get("nanos")
+            .and_then(parse_integer)
+            .ok_or("Expected result.nanos to be an integer.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(parse_integer)
+            .ok_or("Expected result.samples to be an integer.")?;
+
+        let stats = match json.get("stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(stats) => Some(
+                parse_stats(stats)
+                    .ok_or("Expected result.stats to be null or an object of integers.")?,
+            ),
+        };
+
+        let allocs = match json.get("allocs") {
+            None | Some(JsonValue::Null) => None,
+            Some(allocs) => Some(
+                parse_allocs(allocs)
+                    .ok_or("Expected result.allocs to be null or an object of integers.")?,
+            ),
+        };
+
+        let perf = match json.get("perf") {
+            None | Some(JsonValue::Null) => None,
+            Some(perf) => Some(PerfCounters::try_from(perf)?),
+        };
+
+        Ok(PartResult {
+            part,
+            answer,
+            error,
+            duration: Duration::from_nanos(nanos),
+            samples: u128::from(samples),
+            stats,
+            allocs,
+            perf,
+        })
+    }
+}
+
+fn parse_stats(value: &JsonValue) -> Option<BenchStats> {
+    let json = value.get::<HashMap<String, JsonValue>>()?;
+
+    let get = |key: &str| {
+        json.get(key)
+            .and_then(parse_integer)
+            .map(Duration::from_nanos)
+    };
+
+    Some(BenchStats {
+        min: get("min")?,
+        median: get("median")?,
+        mean: get("mean")?,
+        stddev: get("stddev")?,
+        p95: get("p95")?,
+        p99: get("p99")?,
+    })
+}
+
+fn parse_allocs(value: &JsonValue) -> Option<AllocStats> {
+    let json = value.get::<HashMap<String, JsonValue>>()?;
+
+    let get = |key: &str| json.get(key).and_then(parse_integer);
+
+    Some(AllocStats {
+        allocations: get("allocations")?,
+        bytes: get("bytes")?,
+        peak_bytes: get("peak_bytes")?,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Run a solution part from a solution binary. Parts return an `Option` or a `Result`, see [`PartOutput`].
+///
+/// When invoked with `--json`, the human-readable output is replaced by one JSON object per part,
+/// which is how the multi-day runner consumes results of isolated solution runs.
+pub fn run_part<I: Copy, T: PartOutput>(func: impl Fn(I) -> T, input: I, day: Day, part: u8) {
+    let options = RunOptions::from_args();
+
+    if env::args().any(|x| x == "--json") {
+        let result = measure_part(func, input, part, options);
+        println!("{}", JsonValue::from(&result).stringify().unwrap());
+        return;
+    }
+
+    let result = execute_part(func, input, day, part, options, None);
+
+    if let Some(answer) = result.answer {
+        submit_result(answer, day, part);
+    }
+}
+
+/// Run the parse step declared via `solution!` from a solution binary, see [`run_part`].
+pub fn run_parse<I: Copy, T>(func: impl Fn(I) -> T, input: I) {
+    let options = RunOptions::from_args();
+
+    if env::args().any(|x| x == "--json") {
+        let (_, result) = run_timed(func, input, PARSE_STEP, options, |_| false, |_| {});
+        println!("{}", JsonValue::from(&result).stringify().unwrap());
+        return;
+    }
+
+    execute_parse(func, input, options);
+}
+
+/// Run and print the parse step of a solution, returning its timing.
+pub fn execute_parse<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    options: RunOptions,
+) -> PartResult {
+    if options.quiet {
+        return run_timed(func, input, PARSE_STEP, options, |_| false, |_| {}).1;
+    }
+
+    let (_, result) = run_timed(
+        func,
+        input,
+        PARSE_STEP,
+        options,
+        |_| false,
+        |_| {
+            print!("Parse:");
+
+            if options.is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+            }
+
+            let _ = stdout().flush();
+        },
+    );
+
+    print_part_result(&result, None);
+    result
+}
+
+/// Run and print a solution part, returning its answer and timing.
+///
+/// If `answers` are passed, the part is marked with whether it matches its accepted answer.
+pub fn execute_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+    options: RunOptions,
+    answers: Option<&Answers>,
+) -> PartResult {
+    if options.quiet {
+        return measure_part(func, input, part, options);
+    }
+
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (answer, mut result) = run_timed(func, input, part, options, is_failure, |answer| {
+        print_result(&answer.answer(), &part_str, "");
+
+        if options.is_timed && !is_failure(answer) {
+            print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+    });
+
+    set_outcome(&mut result, answer.answer());
+
+    let verified = answers.and_then(|answers| answers.verify(day, part, result.answer.as_deref()));
+    print_part_result(&result, verified);
+
+    result
+}
+
+/// Run a solution part without printing anything.
+fn measure_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    part: u8,
+    options: RunOptions,
+) -> PartResult {
+    let (answer, mut result) = run_timed(func, input, part, options, is_failure, |_| {});
+    set_outcome(&mut result, answer.answer());
+    result
+}
+
+fn is_failure<T: PartOutput>(answer: &T) -> bool {
+    answer.answer().is_err()
+}
+
+fn set_outcome(result: &mut PartResult, outcome: Result<Option<String>, String>) {
+    match outcome {
+        Ok(answer) => result.answer = answer,
+        Err(error) => result.error = Some(error),
+    }
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Print a result that was produced elsewhere, e.g. by an isolated solution binary.
+///
+/// `verified` tells whether the answer matches the accepted answer of the part, if one is known.
+pub fn print_part_result(result: &PartResult, verified: Option<bool>) {
+    if result.part == PARSE_STEP {
+        print_parse(&format_duration(&result.duration, result.samples));
+    } else {
+        let marker = match verified {
+            Some(true) => " ✔",
+            Some(false) => " ✘",
+            None => "",
+        };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        print_result(
+            &result.outcome(),
+            &format!("Part {}", result.part),
+            &format!(
+                "{}{marker}",
+                format_duration(&result.duration, result.samples)
+            ),
+        );
     }
+
+    print_details(result);
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     and the configured statistic of the samples is reported.
+///
+/// Runs that `failed` are not benched, the failure would be timed instead of the solution.
+/// Allocations and hardware counters are only recorded for the first execution, so they are not skewed by benching.
+/// The returned result has no answer, as `func` may not produce one.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    part: u8,
+    options: RunOptions,
+    failed: impl Fn(&T) -> bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, PartResult) {
     let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+    let ((value, allocs), perf) = perf::measure(options.perf, || {
+        allocations::measure(|| {
+            #[cfg(feature = "dhat-heap")]
+            let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
-    };
+            func(input)
+        })
+    });
     let base_time = timer.elapsed();
 
-    hook(&result);
+    hook(&value);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let mut result = PartResult {
+        part,
+        answer: None,
+        error: None,
+        duration: base_time,
+        samples: 1,
+        stats: None,
+        allocs,
+        perf,
     };
 
-    (result, run.0, run.1)
-}
+    if options.is_timed && !failed(&value) {
+        let (stats, samples) = bench(func, input, &base_time, options.warmup);
+        result.duration = stats.get(options.statistic);
+        result.samples = samples;
+        result.stats = Some(stats);
+    }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+    (value, result)
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    warmup: u32,
+) -> (BenchStats, u128) {
+    for _ in 0..warmup {
+        black_box(func(black_box(input)));
+    }
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
@@ -68,20 +567,11 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     }
 
     (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
+        BenchStats::from_samples(&timers).expect("benching collects at least 10 samples"),
         bench_iterations,
     )
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
 fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
@@ -90,11 +580,31 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_parse(duration_str: &str) {
+    print!("\r");
+    println!("Parse:{duration_str}");
+}
+
+/// Print bench statistics, heap usage and hardware counters below a part, if recorded.
+fn print_details(result: &PartResult) {
+    if let Some(stats) = &result.stats {
+        println!("        {ANSI_ITALIC}{stats}{ANSI_RESET}");
+    }
+
+    if let Some(allocs) = &result.allocs {
+        println!("        {ANSI_ITALIC}{allocs}{ANSI_RESET}");
+    }
+
+    if let Some(perf) = &result.perf {
+        println!("        {ANSI_ITALIC}{perf}{ANSI_RESET}");
+    }
+}
+
+fn print_result(result: &Result<Option<String>, String>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -114,7 +624,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,21 +632,26 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(error) => {
+            let str = format!("{part}: ✖ {error}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}{duration_str}");
+            }
+        }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured or aoc-cli is installed.
+fn submit_result<T: Display>(result: T, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +667,135 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
+    let source = InputSource::from_args();
+
+    if !source.is_puzzle() {
         eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            "Not submitting, the answer was computed from {source} instead of the puzzle input."
         );
-        process::exit(1);
+        return;
+    }
+
+    submit_answer(&result.to_string(), day, part);
+}
+
+/// Submit an answer, unless the ledger of previous answers knows it to be wrong.
+fn submit_answer(answer: &str, day: Day, part: u8) {
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("failed to read answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    match answers.check(day, part, answer) {
+        Check::New => {}
+        Check::Solved(correct) => {
+            if correct == answer {
+                println!("Part {part} is solved already, with this answer.");
+            } else {
+                println!(
+                    "Part {part} is solved already, with the answer {correct}. Not submitting."
+                );
+            }
+            return;
+        }
+        Check::KnownWrong(verdict) => {
+            eprintln!(
+                "{answer} was submitted before and was {}. Not submitting it again.",
+                verdict.describe()
+            );
+            process::exit(1);
+        }
+        Check::OutOfBounds { verdict, bound } => {
+            eprintln!(
+                "Warning: {answer} is likely {} as well, as {bound} was {}.",
+                verdict.describe(),
+                verdict.describe()
+            );
+        }
+    }
+
+    let verdict = match Backend::detect() {
+        Ok(Backend::Native(client)) => submit_natively(&client, day, part, answer),
+        Ok(Backend::AocCli) => submit_via_aoc_cli(day, part, answer),
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Some(verdict) = verdict {
+        answers.record(day, part, answer, verdict);
+        if let Err(e) = answers.store_file() {
+            eprintln!("failed to store answers: {e}");
+        }
     }
+}
+
+fn submit_natively(client: &AocClient, day: Day, part: u8, answer: &str) -> Option<Verdict> {
+    println!("Submitting result...");
+
+    match client.submit(day, part, answer) {
+        Ok(Submission::AlreadySolved) => {
+            println!("{}", Submission::AlreadySolved);
+            solved_verdict(client, day, part, answer)
+        }
+        Ok(submission) => {
+            println!("{submission}");
+            Verdict::from_submission(&submission)
+        }
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            if !matches!(e, AocClientError::RateLimited { .. }) {
+                process::exit(1);
+            }
+            Some(Verdict::RateLimited)
+        }
+    }
+}
 
+/// Solved parts are not checked again, so `answer` is compared to the one on the puzzle page instead.
+fn solved_verdict(client: &AocClient, day: Day, part: u8, answer: &str) -> Option<Verdict> {
+    match client.solved_answer(day, part) {
+        Ok(Some(solved)) if solved == answer => {
+            println!("{answer} is the accepted answer.");
+            Some(Verdict::Correct)
+        }
+        Ok(Some(solved)) => {
+            println!("The accepted answer was {solved}.");
+            None
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("failed to look up the accepted answer: {e}");
+            None
+        }
+    }
+}
+
+fn submit_via_aoc_cli(day: Day, part: u8, answer: &str) -> Option<Verdict> {
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+
+    let output = match aoc_cli::submit(day, part, answer) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        }
+    };
+
+    // NOTE: aoc-cli does not show the accepted answer of a solved part, so answers of solved parts are not recorded.
+    match parse_cli_submission(&String::from_utf8_lossy(&output.stdout)) {
+        Ok(submission) => Verdict::from_submission(&submission),
+        Err(AocClientError::RateLimited { .. }) => Some(Verdict::RateLimited),
+        Err(_) => {
+            eprintln!("Warning: could not read the verdict of aoc-cli, it is not recorded.");
+            None
+        }
+    }
 }
@@ -0,0 +1,202 @@
+/// Summary statistics over the samples collected when benching a solution part.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Summary of a set of benchmark samples.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl BenchStats {
+    /// Computes statistics over `samples`, returns [`None`] if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let len = nanos.len();
+
+        let median = if len.is_multiple_of(2) {
+            u128::midpoint(nanos[len / 2 - 1], nanos[len / 2])
+        } else {
+            nanos[len / 2]
+        };
+
+        let mean = nanos.iter().sum::<u128>() / len as u128;
+
+        // NOTE: sample standard deviation, a single sample does not deviate.
+        #[allow(clippy::cast_precision_loss)]
+        let stddev = if len > 1 {
+            let variance = nanos
+                .iter()
+                .map(|&x| (x as f64 - mean as f64).powi(2))
+                .sum::<f64>()
+                / (len - 1) as f64;
+            variance.sqrt()
+        } else {
+            0_f64
+        };
+
+        Some(Self {
+            min: to_duration(nanos[0]),
+            median: to_duration(median),
+            mean: to_duration(mean),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            stddev: Duration::from_nanos(stddev.round() as u64),
+            p95: to_duration(percentile(&nanos, 95)),
+            p99: to_duration(percentile(&nanos, 99)),
+        })
+    }
+
+    /// Returns the value of the chosen [`Statistic`].
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Min => self.min,
+            Statistic::Median => self.median,
+            Statistic::Mean => self.mean,
+            Statistic::P95 => self.p95,
+            Statistic::P99 => self.p99,
+        }
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1?} · median {:.1?} · mean {:.1?} · σ {:.1?} · p95 {:.1?} · p99 {:.1?}",
+            self.min, self.median, self.mean, self.stddev, self.p95, self.p99
+        )
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u128], p: usize) -> u128 {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn to_duration(nanos: u128) -> Duration {
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The statistic that is reported as the timing of a benched part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Statistic {
+    Min,
+    Median,
+    #[default]
+    Mean,
+    P95,
+    P99,
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Statistic::Min => "min",
+            Statistic::Median => "median",
+            Statistic::Mean => "mean",
+            Statistic::P95 => "p95",
+            Statistic::P99 => "p99",
+        })
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = StatisticFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "min" => Ok(Statistic::Min),
+            "median" => Ok(Statistic::Median),
+            "mean" => Ok(Statistic::Mean),
+            "p95" => Ok(Statistic::P95),
+            "p99" => Ok(Statistic::P99),
+            _ => Err(StatisticFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Statistic`].
+#[derive(Debug)]
+pub struct StatisticFromStrError;
+
+impl Error for StatisticFromStrError {}
+
+impl Display for StatisticFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `min`, `median`, `mean`, `p95` or `p99`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchStats, Statistic};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.p99, Duration::from_nanos(42));
+    }
+
+    #[test]
+    fn is_robust_against_outliers() {
+        let mut samples = vec![10; 99];
+        samples.push(100_000);
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(10));
+        assert_eq!(stats.mean, Duration::from_nanos(1009));
+        assert_eq!(stats.p95, Duration::from_nanos(10));
+        assert_eq!(stats.p99, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[5, 1, 4, 2, 3, 6])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+        assert_eq!(stats.p95, Duration::from_nanos(6));
+        assert_eq!(stats.get(Statistic::Min), Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn parses_statistics() {
+        assert_eq!("median".parse::<Statistic>().unwrap(), Statistic::Median);
+        assert_eq!("p99".parse::<Statistic>().unwrap(), Statistic::P99);
+        assert_eq!("avg".parse::<Statistic>().is_err(), true);
+    }
+}
@@ -0,0 +1,247 @@
+/// Templates that `cargo scaffold` creates solution modules from.
+use std::{fs, path::Path};
+
+use crate::template::examples::Examples;
+use crate::template::{Day, Year};
+
+/// Templates that are compiled into the runner, from `src/templates`.
+const BUILTIN_TEMPLATES: [(&str, &str); 4] = [
+    (
+        "default",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/default.txt"
+        )),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "lines",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/lines.txt"
+        )),
+    ),
+];
+
+/// Templates in this directory are read at runtime and take precedence over built-in ones of the same name.
+const USER_TEMPLATES_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Reads the template called `name`, preferring a user template over a built-in one.
+pub fn load(name: &str) -> Result<String, String> {
+    let user_path = Path::new(USER_TEMPLATES_DIR).join(format!("{name}.txt"));
+
+    if let Ok(template) = fs::read_to_string(&user_path) {
+        return Ok(template);
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| (*template).to_string())
+        .ok_or_else(|| {
+            format!(
+                "unknown template `{name}`, expected one of {}.",
+                names()
+                    .iter()
+                    .map(|x| format!("`{x}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// Names of all built-in and user templates, sorted.
+pub fn names() -> Vec<String> {
+    let user_templates = fs::read_dir(USER_TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            (path.extension()? == "txt").then_some(path.file_stem()?.to_str()?.to_string())
+        });
+
+    let mut names: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .chain(user_templates)
+        .collect();
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Values that are substituted into templates.
+#[derive(Debug)]
+pub struct Placeholders {
+    pub day: Day,
+    pub year: Option<u16>,
+    /// Title of the downloaded puzzle, e.g. `Day 1: Historian Hysteria`.
+    pub title: Option<String>,
+    /// Expected answers of the examples, see [`crate::template::examples`].
+    pub answers: [Option<String>; 2],
+}
+
+impl Placeholders {
+    /// Collects placeholders for `day` from the environment and the downloaded puzzle, if there is one.
+    pub fn for_day(day: Day, puzzle: Option<&str>) -> Self {
+        let examples = puzzle.map(crate::template::examples::extract);
+
+        let answers = examples.map_or_else(Default::default, |Examples { part_one, part_two }| {
+            [part_one.answer, part_two.and_then(|x| x.answer)]
+        });
+
+        Self {
+            day,
+            year: day.resolved_year().map(Year::into_inner),
+            title: puzzle.and_then(puzzle_title),
+            answers,
+        }
+    }
+}
+
+/// Substitutes placeholders in `template`:
+///
+/// - `%DAY_NUMBER%` and `%DAY%`: the day, e.g. `1` and `01`.
+/// - `%YEAR%`: the year of the day, see [`Day::resolved_year`].
+/// - `%TITLE%`: the title of the puzzle, or `Day <n>` if it was not downloaded yet.
+/// - `%PART_ONE_TYPE%` and `%PART_TWO_TYPE%`: return types fitting the example answers, `u64` if unknown.
+/// - `%PART_ONE_ANSWER%` and `%PART_TWO_ANSWER%`: the example answers, e.g. `Some(11)`, or `None` if unknown.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let day_number = placeholders.day.into_inner().to_string();
+
+    let title = placeholders
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {day_number}"));
+
+    let [part_one, part_two] = &placeholders.answers;
+
+    template
+        .replace("%DAY_NUMBER%", &day_number)
+        .replace("%DAY%", &placeholders.day.to_string())
+        .replace(
+            "%YEAR%",
+            &placeholders.year.map(|x| x.to_string()).unwrap_or_default(),
+        )
+        .replace("%TITLE%", &title)
+        .replace("%PART_ONE_TYPE%", answer_type(part_one.as_deref()))
+        .replace("%PART_TWO_TYPE%", answer_type(part_two.as_deref()))
+        .replace("%PART_ONE_ANSWER%", &answer_expression(part_one.as_deref()))
+        .replace("%PART_TWO_ANSWER%", &answer_expression(part_two.as_deref()))
+}
+
+fn puzzle_title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find(|line| line.starts_with("## "))?;
+    let title = heading
+        .trim_start_matches("## ")
+        .trim_matches(|c: char| c == '-' || c.is_whitespace());
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+fn answer_type(answer: Option<&str>) -> &'static str {
+    match answer {
+        Some(x) if x.parse::<u64>().is_ok() => "u64",
+        Some(x) if x.parse::<i64>().is_ok() => "i64",
+        Some(_) => "String",
+        None => "u64",
+    }
+}
+
+fn answer_expression(answer: Option<&str>) -> String {
+    match answer {
+        Some(x) if x.parse::<i64>().is_ok() || x.parse::<u64>().is_ok() => format!("Some({x})"),
+        Some(x) => format!("Some({x:?}.to_string())"),
+        None => "None".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{BUILTIN_TEMPLATES, Placeholders, load, puzzle_title, render};
+
+    #[test]
+    fn renders_placeholders() {
+        let placeholders = Placeholders {
+            day: day!(3),
+            year: Some(2024),
+            title: Some("Day 3: Mull It Over".into()),
+            answers: [Some("161".into()), Some("abc".into())],
+        };
+
+        let template = "%TITLE% %YEAR% %DAY% %DAY_NUMBER% Option<%PART_ONE_TYPE%> %PART_ONE_ANSWER% Option<%PART_TWO_TYPE%> %PART_TWO_ANSWER%";
+
+        assert_eq!(
+            render(template, &placeholders),
+            "Day 3: Mull It Over 2024 03 3 Option<u64> Some(161) Option<String> Some(\"abc\".to_string())"
+        );
+    }
+
+    #[test]
+    fn renders_defaults() {
+        let placeholders = Placeholders {
+            day: day!(12),
+            year: None,
+            title: None,
+            answers: [None, None],
+        };
+
+        assert_eq!(
+            render("%TITLE% %PART_TWO_TYPE% %PART_TWO_ANSWER%", &placeholders),
+            "Day 12 u64 None"
+        );
+    }
+
+    #[test]
+    fn leaves_no_placeholders_in_builtin_templates() {
+        let placeholders = Placeholders {
+            day: day!(1),
+            year: Some(2024),
+            title: None,
+            answers: [None, None],
+        };
+
+        for (name, template) in BUILTIN_TEMPLATES {
+            assert_eq!(
+                render(template, &placeholders).contains('%'),
+                false,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn loads_builtin_templates() {
+        assert_eq!(load("grid").is_ok(), true);
+        assert_eq!(load("does-not-exist").is_err(), true);
+    }
+
+    #[test]
+    fn parses_puzzle_titles() {
+        assert_eq!(
+            puzzle_title("## --- Day 1: Historian Hysteria ---\n\nText"),
+            Some("Day 1: Historian Hysteria".into())
+        );
+        assert_eq!(puzzle_title("No heading"), None);
+    }
+}
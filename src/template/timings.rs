@@ -1,17 +1,48 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
 use crate::template::Day;
+use crate::template::allocations::AllocStats;
+use crate::template::perf::PerfCounters;
+use crate::template::stats::Statistic;
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
+/// Version of the `timings.json` schema written by [`Timings::store_file`].
+///
+/// - Version 1 stored parts as display strings such as `"74.13ms"` and had no `version` key.
+/// - Version 2 stores nanoseconds and sample counts per part and the statistic used per day.
+///   Parts may carry heap usage under `allocs` and hardware counters under `perf`, both are
+///   omitted when they were not recorded.
+/// - Version 3 records whether a day was stopped by a timeout under `timed_out`. Version 2 files are
+///   read as if no day timed out.
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Represents the benchmark time of a single part or parse step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartTiming {
+    pub nanos: u64,
+    /// Number of bench samples. Unknown for timings migrated from schema version 1.
+    pub samples: Option<u64>,
+    /// Heap usage, if the runner was built with the `count-allocs` feature.
+    pub allocs: Option<AllocStats>,
+    /// Hardware counters, if the part was run with `--perf`.
+    pub perf: Option<PerfCounters>,
+}
+
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// The statistic of the bench samples that the timings of this day represent.
+    pub statistic: Statistic,
+    /// Time spent in the parse step, if the day declares one. Part timings include it.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
+    /// Whether the day was stopped by a timeout. Parts that did not finish are left empty.
+    pub timed_out: bool,
 }
 
 /// Represents benchmark times for a set of days.
@@ -51,7 +82,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -67,12 +98,76 @@ impl Timings {
     }
 }
 
+impl Timing {
+    /// The stored timing of a part, if it was solved.
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The stored timing of a part in nanoseconds, if it was solved.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        #[allow(clippy::cast_precision_loss)]
+        self.part(part).map(|timing| timing.nanos as f64)
+    }
+}
+
+impl PartTiming {
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+}
+
+impl Display for PartTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1?}", self.duration())
+    }
+}
+
+/// Parses a duration formatted by [`std::time::Duration`]'s `Debug` impl (e.g. `74.13ms`) into nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse = |postfix: &str| s.strip_suffix(postfix)?.parse::<f64>().ok();
+
+    match s {
+        s if s.ends_with("ns") => parse("ns"),
+        s if s.ends_with("µs") => parse("µs").map(|x| x * 1000_f64),
+        s if s.ends_with("ms") => parse("ms").map(|x| x * 1_000_000_f64),
+        _ => parse("s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+/// JSON numbers are floats, only accept the ones that hold a non-negative integer.
+pub(crate) fn parse_integer(value: &JsonValue) -> Option<u64> {
+    let value = *value.get::<f64>()?;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (value >= 0_f64 && value.fract() == 0_f64).then_some(value as u64)
+}
+
+fn parse_allocs(value: &JsonValue) -> Option<AllocStats> {
+    let json = value.get::<HashMap<String, JsonValue>>()?;
+
+    Some(AllocStats {
+        allocations: json.get("allocations").and_then(parse_integer)?,
+        bytes: json.get("bytes").and_then(parse_integer)?,
+        peak_bytes: json.get("peak_bytes").and_then(parse_integer)?,
+    })
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
+
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -87,51 +182,155 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
-        let json_data = json
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files written before the schema was versioned do not contain a version.
+        let version = match json.get("version") {
+            None => 1,
+            Some(v) => parse_integer(v).ok_or("expected `json.version` to be an integer.")?,
+        };
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
-        Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
-        })
+        let parse_timing: fn(&JsonValue) -> Result<Timing, String> = match version {
+            1 => |v| Timing::try_from_v1(v),
+            2 => |v| Timing::try_from_v2(v),
+            3 => |v| Timing::try_from(v),
+            v => return Err(format!("unsupported timings schema version {v}.")),
+        };
+
+        let mut data: Vec<Timing> = json_data
+            .iter()
+            .map(parse_timing)
+            .collect::<Result<_, _>>()?;
+
+        // NOTE: earlier versions stored days that are not part of the event, e.g. day 13 of 2025.
+        data.retain(|timing| timing.day.is_in_event());
+
+        Ok(Timings { data })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-impl From<&Timing> for JsonValue {
-    fn from(value: &Timing) -> Self {
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("nanos".into(), JsonValue::Number(value.nanos as f64));
 
+        #[allow(clippy::cast_precision_loss)]
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "samples".into(),
+            value
+                .samples
+                .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
         );
 
+        if let Some(allocs) = value.allocs {
+            let mut allocs_map: HashMap<String, JsonValue> = HashMap::new();
+
+            for (key, value) in [
+                ("allocations", allocs.allocations),
+                ("bytes", allocs.bytes),
+                ("peak_bytes", allocs.peak_bytes),
+            ] {
+                #[allow(clippy::cast_precision_loss)]
+                allocs_map.insert(key.into(), JsonValue::Number(value as f64));
+            }
+
+            map.insert("allocs".into(), JsonValue::Object(allocs_map));
+        }
+
+        if let Some(perf) = &value.perf {
+            map.insert("perf".into(), JsonValue::from(perf));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(parse_integer)
+            .ok_or("Expected part timing.nanos to be an integer.")?;
+
+        let samples = match json.get("samples") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => {
+                Some(parse_integer(v).ok_or("Expected part timing.samples to be an integer.")?)
+            }
+        };
+
+        let allocs = match json.get("allocs") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(parse_allocs(v).ok_or(
+                "Expected part timing.allocs to be an object with allocations, bytes and peak_bytes.",
+            )?),
+        };
+
+        let perf = match json.get("perf") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(PerfCounters::try_from(v)?),
+        };
+
+        Ok(PartTiming {
+            nanos,
+            samples,
+            allocs,
+            perf,
+        })
+    }
+}
+
+impl From<&Timing> for JsonValue {
+    fn from(value: &Timing) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "statistic".into(),
+            JsonValue::String(value.statistic.to_string()),
         );
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+
+        for (key, part) in [
+            ("parse", &value.parse),
+            ("part_1", &value.part_1),
+            ("part_2", &value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
 
         JsonValue::Object(map)
     }
@@ -148,29 +347,109 @@ impl TryFrom<&JsonValue> for Timing {
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
+            .and_then(|day| Day::from_str_outside_event(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let statistic = json
+            .get("statistic")
+            .and_then(|v| v.get::<String>())
+            .and_then(|statistic| Statistic::from_str(statistic).ok())
+            .ok_or("Expected timing.statistic to be a statistic.")?;
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let get_part = |key: &str| match json.get(key) {
+            Some(JsonValue::Null) => Ok(None),
+            Some(v) => PartTiming::try_from(v).map(Some),
+            None => Err(format!("Expected timing.{key} to be null or an object.")),
+        };
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<bool>().copied())
+            .ok_or("Expected timing.timed_out to be a boolean.")?;
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            statistic,
+            parse: get_part("parse")?,
+            part_1: get_part("part_1")?,
+            part_2: get_part("part_2")?,
             total_nanos,
+            timed_out,
+        })
+    }
+}
+
+impl Timing {
+    /// Migrates a timing of schema version 2, which did not have to record timeouts.
+    fn try_from_v2(value: &JsonValue) -> Result<Self, String> {
+        let mut json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?
+            .clone();
+
+        // NOTE: version 2 files written after timeouts were added already contain the flag.
+        json.entry("timed_out".into())
+            .or_insert(JsonValue::Boolean(false));
+
+        Timing::try_from(&JsonValue::Object(json))
+    }
+
+    /// Migrates a timing of schema version 1, which stored parts as display strings.
+    fn try_from_v1(value: &JsonValue) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_outside_event(day).ok())
+            .ok_or("Expected timing.day to be a Day struct.")?;
+
+        let get_part = |key: &str| -> Result<Option<PartTiming>, String> {
+            let value = match json.get(key) {
+                Some(JsonValue::Null) => return Ok(None),
+                Some(JsonValue::String(value)) => value,
+                _ => return Err(format!("Expected timing.{key} to be null or string.")),
+            };
+
+            let nanos =
+                parse_duration(value).ok_or(format!("Expected timing.{key} to be a duration."))?;
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Ok(Some(PartTiming {
+                nanos: nanos.round() as u64,
+                samples: None,
+                allocs: None,
+                perf: None,
+            }))
+        };
+
+        // NOTE: the parse step was added shortly before the schema was versioned and may be missing.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(_) => get_part("parse")?,
+        };
+
+        let total_nanos = json
+            .get("total_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected timing.total_nanos to be a number.")?;
+
+        Ok(Timing {
+            day,
+            // NOTE: version 1 did not record the statistic, it was the mean for most of its lifetime.
+            statistic: Statistic::Mean,
+            parse,
+            part_1: get_part("part_1")?,
+            part_2: get_part("part_2")?,
+            total_nanos,
+            timed_out: false,
         })
     }
 }
@@ -180,49 +459,133 @@ impl TryFrom<&JsonValue> for Timing {
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
+    use crate::template::stats::Statistic;
+
+    use super::{PartTiming, Timing, Timings};
 
-    use super::{Timing, Timings};
+    pub fn ms(millis: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1_000_000,
+            samples: Some(10),
+            allocs: None,
+            perf: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(10),
+                    part_2: ms(20),
                     total_nanos: 3e+10,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(30),
+                    part_2: ms(40),
                     total_nanos: 7e+10,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(40),
                     part_2: None,
                     total_nanos: 4e+10,
+                    timed_out: false,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{
+                stats::Statistic,
+                timings::{PartTiming, Timings},
+            },
+        };
 
         #[test]
         fn handles_json_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "statistic": "median", "parse": null, "part_1": { "nanos": 1000000, "samples": 500 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.statistic, Statistic::Median);
+            assert_eq!(timing.parse, None);
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    nanos: 1_000_000,
+                    samples: Some(500),
+                    allocs: None,
+                    perf: None,
+                })
+            );
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_timed_out_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0, "timed_out": true }, { "day": "02", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].timed_out, true);
+            assert_eq!(timings.data[1].timed_out, false);
+        }
+
+        #[test]
+        fn migrates_unversioned_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.statistic, Statistic::Mean);
+            assert_eq!(timing.parse, None);
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    nanos: 1_000_000,
+                    samples: None,
+                    allocs: None,
+                    perf: None,
+                })
+            );
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn migrates_unversioned_parse_timings() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "200µs", "part_1": "74.13ns", "part_2": "2s", "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.unwrap().nanos, 200_000);
+            assert_eq!(timing.part_1.unwrap().nanos, 74);
+            assert_eq!(timing.part_2.unwrap().nanos, 2_000_000_000);
+        }
+
+        #[test]
+        fn skips_days_outside_the_event() {
+            // NOTE: cargo sets `AOC_YEAR` to 2025 via `.cargo/config.toml`, which has 12 days.
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0 }, { "day": "13", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0 }, { "day": "2026/13", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0 }, { "day": "2024/13", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let days: Vec<String> = timings.data.iter().map(|t| t.day.to_string()).collect();
+            assert_eq!(days, vec!["01", "2024/13"]);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -230,6 +593,18 @@ mod tests {
             assert_eq!(timings.data.len(), 0);
         }
 
+        #[test]
+        fn roundtrips_timings() {
+            let timings = super::get_mock_timings();
+            let json = tinyjson::JsonValue::from(timings.clone())
+                .stringify()
+                .unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), timings.data.len());
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_invalid_json() {
@@ -243,6 +618,27 @@ mod tests {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_missing_timeouts() {
+            let json = r#"{ "version": 3, "data": [{ "day": "01", "statistic": "mean", "parse": null, "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_part_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "statistic": "mean", "parse": null, "part_1": "1ms", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_versions() {
+            let json = r#"{ "version": 4, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
     }
 
     mod serialization {
@@ -269,9 +665,13 @@ mod tests {
     }
 
     mod is_day_complete {
+        use super::ms;
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                stats::Statistic,
+                timings::{Timing, Timings},
+            },
         };
 
         #[test]
@@ -279,13 +679,16 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(1),
+                    part_2: ms(2),
                     total_nanos: 3_000_000_000_f64,
+                    timed_out: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +696,16 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    statistic: Statistic::Mean,
+                    parse: None,
+                    part_1: ms(1),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    timed_out: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,20 +713,78 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    statistic: Statistic::Mean,
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    timed_out: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod part_nanos {
+        use crate::{
+            day,
+            template::{stats::Statistic, timings::Timing},
+        };
+
+        use super::ms;
+
+        #[test]
+        fn returns_stored_nanos() {
+            let timing = Timing {
+                day: day!(1),
+                statistic: Statistic::Mean,
+                parse: None,
+                part_1: ms(74),
+                part_2: ms(2000),
+                total_nanos: 0_f64,
+                timed_out: false,
+            };
+            assert_eq!(timing.part_nanos(1), Some(74_000_000_f64));
+            assert_eq!(timing.part_nanos(2), Some(2_000_000_000_f64));
+        }
+
+        #[test]
+        fn handles_missing_parts() {
+            let timing = Timing {
+                day: day!(1),
+                statistic: Statistic::Mean,
+                parse: None,
+                part_1: None,
+                part_2: ms(1),
+                total_nanos: 0_f64,
+                timed_out: false,
+            };
+            assert_eq!(timing.part_nanos(1), None);
+            assert_eq!(timing.part_nanos(3), None);
+        }
+    }
+
+    mod parse_duration {
+        use crate::template::timings::parse_duration;
+
+        #[test]
+        fn parses_debug_durations() {
+            assert_eq!(parse_duration("74.13ns"), Some(74.13));
+            assert_eq!(parse_duration("1.5µs"), Some(1500_f64));
+            assert_eq!(parse_duration("74.5ms"), Some(74_500_000_f64));
+            assert_eq!(parse_duration("2s"), Some(2_000_000_000_f64));
+            assert_eq!(parse_duration("garbage"), None);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                stats::Statistic,
+                timings::{Timing, Timings},
+            },
         };
 
         use super::get_mock_timings;
@@ -331,9 +795,12 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    statistic: Statistic::Mean,
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    timed_out: false,
                 }],
             };
             let merged = timings.merge(&other);
@@ -351,9 +818,12 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    statistic: Statistic::Mean,
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    timed_out: false,
                 }],
             };
             let merged = timings.merge(&other);
@@ -0,0 +1,430 @@
+/// Waits for changes to the files that a solution is built and run from, for `cargo solve --watch`.
+use std::collections::BTreeSet;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::template::Day;
+use crate::template::runner::{PARSE_STEP, PartResult};
+
+/// Directories that contain files relevant to `day`. Directories are watched instead of files,
+/// as editors often save by replacing a file, which would end a watch on the file itself.
+fn watched_dirs(day: Day) -> [String; 4] {
+    let data_dir = |kind: &str| match day.year() {
+        Some(year) => format!("data/{kind}/{year}"),
+        None => format!("data/{kind}"),
+    };
+
+    [
+        "src".into(),
+        "src/bin".into(),
+        data_dir("inputs"),
+        data_dir("examples"),
+    ]
+}
+
+/// Time without further changes after which a burst of changes is considered complete.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Watches the solution, library and data files of a single day.
+pub struct Watcher {
+    day: Day,
+    events: sys::Events,
+}
+
+impl Watcher {
+    pub fn new(day: Day) -> Result<Self, Error> {
+        let dirs = watched_dirs(day);
+        let dirs: Vec<&Path> = dirs
+            .iter()
+            .map(Path::new)
+            .filter(|dir| dir.is_dir())
+            .collect();
+
+        if dirs.is_empty() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "none of the watched directories exist",
+            ));
+        }
+
+        Ok(Self {
+            day,
+            events: sys::Events::new(&dirs)?,
+        })
+    }
+
+    /// Blocks until relevant files changed, returning them once no more changes follow for [`DEBOUNCE`].
+    pub fn wait(&mut self) -> Result<BTreeSet<PathBuf>, Error> {
+        let day = self.day;
+        let relevant = |paths: Vec<PathBuf>| paths.into_iter().filter(move |x| is_relevant(day, x));
+
+        let mut changed = BTreeSet::new();
+
+        while changed.is_empty() {
+            changed.extend(relevant(self.events.read(None)?));
+        }
+
+        // NOTE: editors write files in several steps, e.g. a temporary file that is renamed.
+        loop {
+            let paths = self.events.read(Some(DEBOUNCE))?;
+
+            if paths.is_empty() {
+                return Ok(changed);
+            }
+
+            changed.extend(relevant(paths));
+        }
+    }
+}
+
+/// Whether a changed file affects the solution of `day`: its module, its input and examples, or any library module.
+fn is_relevant(day: Day, path: &Path) -> bool {
+    let (Some(dir), Some(name)) = (
+        path.parent().and_then(Path::to_str),
+        path.file_name().and_then(|x| x.to_str()),
+    ) else {
+        return false;
+    };
+
+    let [src, bin, inputs, examples] = watched_dirs(day);
+    let number = format!("{:02}", day.into_inner());
+
+    if dir == src {
+        name.ends_with(".rs") && name != "main.rs"
+    } else if dir == bin {
+        name == format!("{}.rs", day.bin_name())
+    } else if dir == inputs {
+        name == format!("{number}.txt")
+    } else if dir == examples {
+        name == format!("{number}.txt")
+            || (name.starts_with(&format!("{number}-")) && name.ends_with(".txt"))
+    } else {
+        false
+    }
+}
+
+/// Describes how the answers of `current` differ from the answers of `previous`, one line per changed part.
+pub fn diff_answers(previous: &[PartResult], current: &[PartResult]) -> Vec<String> {
+    let answer = |results: &[PartResult], part: u8| {
+        results
+            .iter()
+            .find(|result| result.part == part)
+            .and_then(|result| result.answer.clone())
+    };
+
+    let parts: BTreeSet<u8> = previous
+        .iter()
+        .chain(current)
+        .map(|result| result.part)
+        .filter(|part| *part != PARSE_STEP)
+        .collect();
+
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            let (before, after) = (answer(previous, part), answer(current, part));
+
+            (before != after).then(|| {
+                format!(
+                    "Part {part}: {} → {}",
+                    before.as_deref().unwrap_or("✖"),
+                    after.as_deref().unwrap_or("✖")
+                )
+            })
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::ffi::{CString, OsStr};
+    use std::io::{Error, ErrorKind};
+    use std::mem::size_of;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
+
+    const WATCH_MASK: u32 = libc::IN_CLOSE_WRITE
+        | libc::IN_MODIFY
+        | libc::IN_CREATE
+        | libc::IN_DELETE
+        | libc::IN_MOVED_TO
+        | libc::IN_MOVED_FROM;
+
+    /// Events of an inotify instance that watches directories.
+    pub struct Events {
+        fd: OwnedFd,
+        /// Watched directories, by their watch descriptor.
+        dirs: Vec<(libc::c_int, PathBuf)>,
+    }
+
+    impl Events {
+        pub fn new(dirs: &[&Path]) -> Result<Self, Error> {
+            // SAFETY: plain syscall without pointers.
+            let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC | libc::IN_NONBLOCK) };
+
+            if fd < 0 {
+                return Err(Error::last_os_error());
+            }
+
+            // SAFETY: the descriptor was just created and is owned by nothing else.
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+            let dirs = dirs
+                .iter()
+                .map(|dir| {
+                    let path = CString::new(dir.as_os_str().as_bytes())
+                        .map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+
+                    // SAFETY: `path` is a valid, nul-terminated string.
+                    let wd = unsafe {
+                        libc::inotify_add_watch(fd.as_raw_fd(), path.as_ptr(), WATCH_MASK)
+                    };
+
+                    if wd < 0 {
+                        return Err(Error::last_os_error());
+                    }
+
+                    Ok((wd, dir.to_path_buf()))
+                })
+                .collect::<Result<_, Error>>()?;
+
+            Ok(Self { fd, dirs })
+        }
+
+        /// Waits up to `timeout`, or indefinitely, for events and returns the paths of the files they concern.
+        pub fn read(&mut self, timeout: Option<Duration>) -> Result<Vec<PathBuf>, Error> {
+            let mut poll_fd = libc::pollfd {
+                fd: self.fd.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+
+            let timeout = timeout.map_or(-1, |x| {
+                libc::c_int::try_from(x.as_millis()).unwrap_or(libc::c_int::MAX)
+            });
+
+            // SAFETY: `poll_fd` is a single, valid `pollfd`.
+            match unsafe { libc::poll(&raw mut poll_fd, 1, timeout) } {
+                0 => return Ok(vec![]),
+                x if x < 0 => {
+                    let error = Error::last_os_error();
+                    return match error.kind() {
+                        ErrorKind::Interrupted => Ok(vec![]),
+                        _ => Err(error),
+                    };
+                }
+                _ => {}
+            }
+
+            let mut buffer = [0_u8; 4096];
+
+            // SAFETY: the buffer is valid for writes of its length.
+            let bytes = unsafe {
+                libc::read(
+                    self.fd.as_raw_fd(),
+                    buffer.as_mut_ptr().cast(),
+                    buffer.len(),
+                )
+            };
+
+            if bytes < 0 {
+                let error = Error::last_os_error();
+                return match error.kind() {
+                    ErrorKind::WouldBlock | ErrorKind::Interrupted => Ok(vec![]),
+                    _ => Err(error),
+                };
+            }
+
+            let bytes = usize::try_from(bytes).unwrap_or_default();
+            let buffer = &buffer[..bytes];
+
+            let mut paths = vec![];
+            let mut offset = 0;
+
+            while offset + size_of::<libc::inotify_event>() <= buffer.len() {
+                // SAFETY: the header is in bounds and read without requiring alignment.
+                let event = unsafe {
+                    buffer
+                        .as_ptr()
+                        .add(offset)
+                        .cast::<libc::inotify_event>()
+                        .read_unaligned()
+                };
+
+                let name_start = offset + size_of::<libc::inotify_event>();
+                let name_end = name_start + event.len as usize;
+                offset = name_end;
+
+                let Some(name) = buffer.get(name_start..name_end) else {
+                    break;
+                };
+
+                // NOTE: names are padded with nul bytes.
+                let name = name.split(|x| *x == 0).next().unwrap_or_default();
+
+                if name.is_empty() {
+                    continue;
+                }
+
+                if let Some((_, dir)) = self.dirs.iter().find(|(wd, _)| *wd == event.wd) {
+                    paths.push(dir.join(OsStr::from_bytes(name)));
+                }
+            }
+
+            Ok(paths)
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use std::collections::HashMap;
+    use std::io::Error;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, Instant, SystemTime};
+    use std::{fs, thread};
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Detects changes by comparing modification times, as inotify is only available on Linux.
+    pub struct Events {
+        dirs: Vec<PathBuf>,
+        files: HashMap<PathBuf, SystemTime>,
+    }
+
+    impl Events {
+        pub fn new(dirs: &[&Path]) -> Result<Self, Error> {
+            let dirs: Vec<PathBuf> = dirs.iter().map(|dir| dir.to_path_buf()).collect();
+            let files = snapshot(&dirs);
+            Ok(Self { dirs, files })
+        }
+
+        /// Waits up to `timeout`, or indefinitely, for changes and returns the paths of the files they concern.
+        pub fn read(&mut self, timeout: Option<Duration>) -> Result<Vec<PathBuf>, Error> {
+            let started = Instant::now();
+
+            loop {
+                thread::sleep(POLL_INTERVAL);
+
+                let files = snapshot(&self.dirs);
+
+                let mut changed: Vec<PathBuf> = files
+                    .iter()
+                    .filter(|(path, modified)| self.files.get(*path) != Some(modified))
+                    .map(|(path, _)| path.clone())
+                    .collect();
+
+                changed.extend(
+                    self.files
+                        .keys()
+                        .filter(|path| !files.contains_key(*path))
+                        .cloned(),
+                );
+
+                self.files = files;
+
+                if !changed.is_empty() || timeout.is_some_and(|x| started.elapsed() >= x) {
+                    return Ok(changed);
+                }
+            }
+        }
+    }
+
+    fn snapshot(dirs: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+        dirs.iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                Some((entry.path(), modified))
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use crate::day;
+    use crate::template::Year;
+    use crate::template::runner::PartResult;
+
+    use super::{diff_answers, is_relevant};
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(ToString::to_string),
+            error: None,
+            duration: Duration::ZERO,
+            samples: 1,
+            stats: None,
+            allocs: None,
+            perf: None,
+        }
+    }
+
+    #[test]
+    fn filters_relevant_files() {
+        let day = day!(5);
+
+        for path in [
+            "src/bin/05.rs",
+            "src/lib.rs",
+            "src/grid.rs",
+            "data/inputs/05.txt",
+            "data/examples/05.txt",
+            "data/examples/05-2.txt",
+        ] {
+            assert_eq!(is_relevant(day, Path::new(path)), true, "{path}");
+        }
+
+        for path in [
+            "src/bin/06.rs",
+            "src/main.rs",
+            "src/bin/.05.rs.swp",
+            "data/inputs/06.txt",
+            "data/examples/15.txt",
+            "data/answers.json",
+        ] {
+            assert_eq!(is_relevant(day, Path::new(path)), false, "{path}");
+        }
+
+        let day = day.with_year(Year::new(2024).unwrap());
+
+        for path in [
+            "src/bin/2024-05.rs",
+            "data/inputs/2024/05.txt",
+            "data/examples/2024/05-2.txt",
+        ] {
+            assert_eq!(is_relevant(day, Path::new(path)), true, "{path}");
+        }
+
+        for path in ["src/bin/05.rs", "data/inputs/05.txt"] {
+            assert_eq!(is_relevant(day, Path::new(path)), false, "{path}");
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = [result(0, None), result(1, Some("11")), result(2, None)];
+        let current = [result(0, None), result(1, Some("12")), result(2, None)];
+
+        assert_eq!(diff_answers(&previous, &current), vec!["Part 1: 11 → 12"]);
+        assert_eq!(
+            diff_answers(&current, &[result(1, Some("12")), result(2, Some("31"))]),
+            vec!["Part 2: ✖ → 31"]
+        );
+        assert_eq!(diff_answers(&current, &current).is_empty(), true);
+    }
+}
@@ -0,0 +1,95 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The year of the first advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// A year of advent of code (i.e. an integer from 2015 on).
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The number of puzzles of the event, which has 25 days up to 2024 and 12 days from 2025 on.
+    pub const fn days(self) -> u8 {
+        if self.0 < 2025 { 25 } else { 12 }
+    }
+
+    /// The year set via `AOC_YEAR`, which days without a year of their own belong to.
+    pub fn configured() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent of code, from 2015 on")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(Year::from_str("2024").ok(), Year::new(2024));
+        assert_eq!(Year::from_str("2014").is_err(), true);
+        assert_eq!(Year::from_str("24").is_err(), true);
+    }
+
+    #[test]
+    fn counts_days_of_events() {
+        assert_eq!(Year::new(2015).unwrap().days(), 25);
+        assert_eq!(Year::new(2024).unwrap().days(), 25);
+        assert_eq!(Year::new(2025).unwrap().days(), 12);
+    }
+}